@@ -0,0 +1,91 @@
+use aoc_utils::Solution;
+
+pub struct Day01;
+
+#[aoc_macros::solution(2023, 1)]
+impl Solution for Day01 {
+    type Input = Vec<&'static str>;
+
+    fn parse(input: &'static str) -> Self::Input {
+        input.lines().collect()
+    }
+
+    fn part1(lines: &Self::Input) -> u32 {
+        lines
+            .iter()
+            .map(|line| {
+                let mut it = line.chars().filter_map(|char| char.to_digit(10));
+
+                let first = it.next().expect("First digit expected!");
+                let last = it.last().unwrap_or(first);
+
+                first * 10 + last
+            })
+            .sum()
+    }
+
+    fn part2(lines: &Self::Input) -> u32 {
+        let mapping = [
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+        ];
+
+        lines
+            .iter()
+            .map(|line| {
+                let mut it = (0..line.len()).filter_map(|start| {
+                    let sub_line = &line[start..];
+                    for (value_str, value) in mapping {
+                        if sub_line.starts_with(value_str) {
+                            return Some(value);
+                        }
+                    }
+                    sub_line.chars().next().unwrap().to_digit(10)
+                });
+
+                let first = it.next().expect("First digit expected!");
+                let last = it.last().unwrap_or(first);
+
+                first * 10 + last
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day01);
+
+    #[fixture]
+    fn data1() -> Vec<&'static str> {
+        let input = include_str!("test_input1.txt");
+        Day01::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> Vec<&'static str> {
+        let input = include_str!("test_input2.txt");
+        Day01::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data1: Vec<&'static str>) {
+        assert_eq!(Day01::part1(&data1), 142);
+    }
+
+    #[rstest]
+    fn part2_test(data2: Vec<&'static str>) {
+        assert_eq!(Day01::part2(&data2), 281);
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+use regex::Regex;
+
+pub struct Day02;
+
+#[aoc_macros::solution(2023, 2)]
+impl Solution for Day02 {
+    type Input = Vec<Vec<(u32, u32, u32)>>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let game = Regex::new(r"^Game \d+: (?<sets>.+)$").unwrap();
+        let cubes = Regex::new(r"^(?<count>\d+) (?<color>red|green|blue)$").unwrap();
+
+        input
+            .lines()
+            .map(|line| {
+                let caps = game.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "expected `Game 1: 3 blue, 4 red; 1 red`")
+                })?;
+                caps.name("sets")
+                    .unwrap()
+                    .as_str()
+                    .split("; ")
+                    .map(|set| {
+                        let mut red: u32 = 0;
+                        let mut green: u32 = 0;
+                        let mut blue: u32 = 0;
+
+                        for cube in set.split(", ") {
+                            let caps = cubes.captures(cube).ok_or_else(|| {
+                                ParseError::at(input, cube, "expected a count and a color")
+                            })?;
+                            let count = parse_part(input, caps.name("count").unwrap().as_str())?;
+                            match &caps["color"] {
+                                "red" => red = count,
+                                "green" => green = count,
+                                _ => blue = count,
+                            }
+                        }
+
+                        Ok((red, green, blue))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Input) -> u32 {
+        let max: (u32, u32, u32) = (12, 13, 14);
+        games
+            .iter()
+            .enumerate()
+            .filter_map(|(id, game)| {
+                if game
+                    .iter()
+                    .all(|set| set.0 <= max.0 && set.1 <= max.1 && set.2 <= max.2)
+                {
+                    Some(id as u32 + 1)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn part2(games: &Self::Input) -> u32 {
+        games
+            .iter()
+            .map(|game| {
+                let minimums = game.iter().fold((0, 0, 0), |acc, set| {
+                    (acc.0.max(set.0), acc.1.max(set.1), acc.2.max(set.2))
+                });
+                minimums.0 * minimums.1 * minimums.2
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day02);
+
+    #[fixture]
+    fn data() -> Vec<Vec<(u32, u32, u32)>> {
+        let input = include_str!("test_input.txt");
+        Day02::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<Vec<(u32, u32, u32)>>) {
+        assert_eq!(Day02::part1(&data), 8);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<Vec<(u32, u32, u32)>>) {
+        assert_eq!(Day02::part2(&data), 2286);
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+use regex::Regex;
+
+pub struct Day03;
+
+#[aoc_macros::solution(2023, 3)]
+impl Solution for Day03 {
+    type Input = HashMap<(usize, usize), Vec<u32>>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"\d+").unwrap();
+        let non_chars = HashSet::from(['.', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+
+        let mut chars: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if let Some(line) = lines.iter().find(|line| line.chars().count() != width) {
+            let message = format!("expected a row of {width} characters");
+            return Err(ParseError::at(input, line, message));
+        }
+        let grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+        for (row, line) in lines.iter().enumerate() {
+            for m in re.find_iter(line) {
+                let value: u32 = parse_part(input, m.as_str())?;
+
+                for r in row.saturating_sub(1)..(row + 2).min(grid.len()) {
+                    for c in m.start().saturating_sub(1)..(m.end() + 1).min(grid[row].len()) {
+                        if !non_chars.contains(&grid[r][c]) {
+                            chars.entry((r, c)).or_default().push(value);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(chars)
+    }
+
+    fn part1(chars: &Self::Input) -> u32 {
+        chars
+            .iter()
+            .map(|(_key, value)| value.iter().sum::<u32>())
+            .sum()
+    }
+
+    fn part2(chars: &Self::Input) -> u32 {
+        chars
+            .iter()
+            .filter_map(|(_key, value)| {
+                if value.len() == 2 {
+                    Some(value.iter().product::<u32>())
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day03);
+
+    #[fixture]
+    fn data() -> HashMap<(usize, usize), Vec<u32>> {
+        let input = include_str!("test_input.txt");
+        Day03::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: HashMap<(usize, usize), Vec<u32>>) {
+        assert_eq!(Day03::part1(&data), 4361);
+    }
+
+    #[rstest]
+    fn part2_test(data: HashMap<(usize, usize), Vec<u32>>) {
+        assert_eq!(Day03::part2(&data), 467_835);
+    }
+}
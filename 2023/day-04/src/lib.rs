@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use aoc_utils::{ParseError, Solution};
+use regex::Regex;
+
+pub struct Day04;
+
+#[aoc_macros::solution(2023, 4)]
+impl Solution for Day04 {
+    type Input = Vec<(Vec<u32>, Vec<u32>)>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let re =
+            Regex::new(r"^Card\s+\d+:\s+(?<winning>[\d\s]+)\s+\|\s+(?<found>[\d\s]+)\s*$").unwrap();
+        input
+            .trim_end()
+            .lines()
+            .map(|line| {
+                let caps = re.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "expected `Card 1: 41 48 | 83 86`")
+                })?;
+                let winning = caps["winning"]
+                    .split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect();
+
+                let found = caps["found"]
+                    .split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect();
+
+                Ok((winning, found))
+            })
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input) -> u32 {
+        cards
+            .iter()
+            .map(|card| {
+                let wins = card
+                    .0
+                    .iter()
+                    .collect::<HashSet<_>>()
+                    .intersection(&card.1.iter().collect::<HashSet<_>>())
+                    .count();
+                if wins == 0 {
+                    0
+                } else {
+                    2u32.pow(wins as u32 - 1)
+                }
+            })
+            .sum()
+    }
+
+    fn part2(cards: &Self::Input) -> u32 {
+        let card_wins: Vec<usize> = cards
+            .iter()
+            .map(|card| {
+                card.0
+                    .iter()
+                    .collect::<HashSet<_>>()
+                    .intersection(&card.1.iter().collect::<HashSet<_>>())
+                    .count()
+            })
+            .collect();
+        let mut card_counts = vec![1u32; cards.len()];
+
+        for i in 0..cards.len() {
+            for j in (i + 1)..(i + 1 + card_wins[i]).min(cards.len()) {
+                card_counts[j] += card_counts[i];
+            }
+        }
+
+        card_counts.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day04);
+
+    #[fixture]
+    fn data() -> Vec<(Vec<u32>, Vec<u32>)> {
+        let input = include_str!("test_input.txt");
+        Day04::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<(Vec<u32>, Vec<u32>)>) {
+        assert_eq!(Day04::part1(&data), 13);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<(Vec<u32>, Vec<u32>)>) {
+        assert_eq!(Day04::part2(&data), 30);
+    }
+
+    #[rstest]
+    #[case("Card 1: 41 48 | 83 86\nCard 2: 13 32 | 61 x\n", 2)]
+    #[case("Card 1: 41 48 | 83 86\nCard 2: 13 32\n", 2)]
+    #[case("junk Card 1: 41 48 | 83 86\n", 1)]
+    fn malformed_input(#[case] input: &'static str, #[case] line: usize) {
+        assert_eq!(Day04::try_parse(input).unwrap_err().line, line);
+    }
+}
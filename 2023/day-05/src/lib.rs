@@ -0,0 +1,99 @@
+use aoc_utils::interval::{IntervalMap, IntervalSet};
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+
+pub struct Day05;
+
+#[aoc_macros::solution(2023, 5)]
+impl Solution for Day05 {
+    type Input = (Vec<u64>, Vec<IntervalMap<u64>>);
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let mut blocks_it = input.split("\n\n");
+        let first = blocks_it.next().unwrap_or_default();
+        let (_, seeds) = first
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(input, first, "expected `seeds: 79 14 55 13`"))?;
+        let seeds: Vec<u64> = seeds
+            .split_whitespace()
+            .map(|num| parse_part(input, num))
+            .collect::<Result<_, _>>()?;
+
+        let mappings: Vec<IntervalMap<u64>> = blocks_it
+            .map(|block| {
+                block
+                    .lines()
+                    .skip(1)
+                    .map(|mapping| {
+                        let nums: Vec<u64> = mapping
+                            .split_whitespace()
+                            .map(|num| parse_part(input, num))
+                            .collect::<Result<_, _>>()?;
+                        let [target, source, length] = nums[..] else {
+                            let message = "expected a destination, a source and a length";
+                            return Err(ParseError::at(input, mapping, message));
+                        };
+                        Ok((source..source + length, target))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((seeds, mappings))
+    }
+
+    fn part1(seeds_mappings: &Self::Input) -> u64 {
+        seeds_mappings
+            .0
+            .iter()
+            .map(|seed| {
+                seeds_mappings
+                    .1
+                    .iter()
+                    .fold(*seed, |value, mapping| mapping.map(value))
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn part2(seeds_mappings: &Self::Input) -> u64 {
+        let seeds: IntervalSet<u64> = seeds_mappings
+            .0
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|&[start, len]| start..start + len)
+            .collect();
+        let almanac = seeds_mappings
+            .1
+            .iter()
+            .fold(IntervalMap::new(), |almanac, mapping| almanac.then(mapping));
+
+        almanac.map_set(&seeds).min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day05);
+
+    #[fixture]
+    fn data() -> (Vec<u64>, Vec<IntervalMap<u64>>) {
+        let input = include_str!("test_input.txt");
+        Day05::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: (Vec<u64>, Vec<IntervalMap<u64>>)) {
+        assert_eq!(Day05::part1(&data), 35);
+    }
+
+    #[rstest]
+    fn part2_test(data: (Vec<u64>, Vec<IntervalMap<u64>>)) {
+        assert_eq!(Day05::part2(&data), 46);
+    }
+}
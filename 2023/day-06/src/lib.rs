@@ -0,0 +1,75 @@
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+
+pub struct Day06;
+
+#[aoc_macros::solution(2023, 6)]
+impl Solution for Day06 {
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let (times, distances) = input
+            .split_once('\n')
+            .ok_or_else(|| ParseError::new(input, input.len(), "expected a line of distances"))?;
+        let numbers = |line: &str| {
+            line.split_whitespace()
+                .skip(1)
+                .map(|num| parse_part(input, num))
+                .collect::<Result<Vec<u64>, _>>()
+        };
+
+        Ok((numbers(times)?, numbers(distances)?))
+    }
+
+    fn part1(races: &Self::Input) -> u64 {
+        races
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, time)| (*time, races.1[i]))
+            .map(|(time, distance)| {
+                (0..=time)
+                    .filter(|charge| (time - charge) * charge > distance)
+                    .count() as u64
+            })
+            .product()
+    }
+
+    fn part2(races: &Self::Input) -> u64 {
+        let time = races.0.iter().fold(0u64, |acc, part| {
+            acc * (10u64.pow(part.checked_ilog10().unwrap_or(0) + 1)) + part
+        });
+        let distance = races.1.iter().fold(0u64, |acc, part| {
+            acc * (10u64.pow(part.checked_ilog10().unwrap_or(0) + 1)) + part
+        });
+
+        (0..=time)
+            .filter(|charge| (time - charge) * charge > distance)
+            .count() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day06);
+
+    #[fixture]
+    fn data() -> (Vec<u64>, Vec<u64>) {
+        let input = include_str!("test_input.txt");
+        Day06::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: (Vec<u64>, Vec<u64>)) {
+        assert_eq!(Day06::part1(&data), 288);
+    }
+
+    #[rstest]
+    fn part2_test(data: (Vec<u64>, Vec<u64>)) {
+        assert_eq!(Day06::part2(&data), 71503);
+    }
+}
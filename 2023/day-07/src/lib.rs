@@ -0,0 +1,133 @@
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day07;
+
+#[aoc_macros::solution(2023, 7)]
+impl Solution for Day07 {
+    type Input = Vec<(Vec<u32>, u64)>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a hand and a bid"))?;
+                let cards = hand
+                    .char_indices()
+                    .map(|(i, char)| match char {
+                        'A' => Ok(14),
+                        'K' => Ok(13),
+                        'Q' => Ok(12),
+                        'J' => Ok(11),
+                        'T' => Ok(10),
+                        _ => char.to_digit(10).ok_or_else(|| {
+                            ParseError::at(input, &hand[i..], format!("unknown card {char:?}"))
+                        }),
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((cards, parse_part(input, bid)?))
+            })
+            .collect()
+    }
+
+    fn part1(hands: &Self::Input) -> u64 {
+        hands
+            .iter()
+            .map(|(hand, bid)| (hand, bid, get_hand_type(hand, false)))
+            .sorted_by_key(|hand| (hand.2, hand.0))
+            .enumerate()
+            .map(|(i, (_hand, bid, _hand_type))| (i as u64 + 1) * bid)
+            .sum()
+    }
+
+    fn part2(hands: &Self::Input) -> u64 {
+        hands
+            .iter()
+            .map(|(hand, bid)| (hand, bid, get_hand_type(hand, true)))
+            .sorted_by_key(|hand| {
+                (
+                    hand.2,
+                    hand.0
+                        .iter()
+                        .map(|card| match card {
+                            11 => 1,
+                            _ => *card,
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .enumerate()
+            .map(|(i, (_hand, bid, _hand_type))| (i as u64 + 1) * bid)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+enum HandType {
+    FiveOfAKind = 6,
+    FourOfAKind = 5,
+    FullHouse = 4,
+    ThreeOfAKind = 3,
+    TwoPair = 2,
+    OnePair = 1,
+    HighCard = 0,
+}
+
+fn get_hand_type(hand: &[u32], with_joker: bool) -> HandType {
+    let mut counts = hand.iter().counts();
+    let jokers = if with_joker {
+        counts.remove(&11).unwrap_or(0)
+    } else {
+        0
+    };
+
+    let mut sorted_counts: Vec<_> = counts
+        .values()
+        .sorted_by_key(|&&count| std::cmp::Reverse(count))
+        .copied()
+        .collect();
+    if jokers == 5 {
+        sorted_counts.push(jokers);
+    } else {
+        sorted_counts[0] += jokers;
+    }
+
+    let counts_string = sorted_counts.iter().join("");
+    match &*counts_string {
+        "5" => HandType::FiveOfAKind,
+        "41" => HandType::FourOfAKind,
+        "32" => HandType::FullHouse,
+        "311" => HandType::ThreeOfAKind,
+        "221" => HandType::TwoPair,
+        "2111" => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day07);
+
+    #[fixture]
+    fn data() -> Vec<(Vec<u32>, u64)> {
+        let input = include_str!("test_input.txt");
+        Day07::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<(Vec<u32>, u64)>) {
+        assert_eq!(Day07::part1(&data), 6440);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<(Vec<u32>, u64)>) {
+        assert_eq!(Day07::part2(&data), 5905);
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use aoc_utils::math::lcm_all;
+use aoc_utils::{ParseError, Solution};
+use regex::Regex;
+
+pub struct Day08;
+
+#[aoc_macros::solution(2023, 8)]
+impl Solution for Day08 {
+    type Input = (
+        Vec<usize>,
+        HashMap<&'static str, (&'static str, &'static str)>,
+    );
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let blocks = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                input,
+                input.len(),
+                "expected a blank line after the instructions",
+            )
+        })?;
+
+        let instructions: Vec<usize> = blocks
+            .0
+            .chars()
+            .filter_map(|char| match char {
+                'L' => Some(0),
+                'R' => Some(1),
+                _ => None,
+            })
+            .collect();
+
+        let re = Regex::new(r"^(?<node>\w\w\w) = \((?<left>\w\w\w), (?<right>\w\w\w)\)$").unwrap();
+        let map = blocks
+            .1
+            .trim_end()
+            .lines()
+            .map(|line| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| ParseError::at(input, line, "expected `AAA = (BBB, CCC)`"))?;
+                Ok((
+                    caps.name("node").unwrap().as_str(),
+                    (
+                        caps.name("left").unwrap().as_str(),
+                        caps.name("right").unwrap().as_str(),
+                    ),
+                ))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok((instructions, map))
+    }
+
+    fn part1(instructions_map: &Self::Input) -> u64 {
+        let (instructions, map) = instructions_map;
+
+        let mut current_node = "AAA";
+        instructions
+            .iter()
+            .cycle()
+            .enumerate()
+            .find_map(|(i, instruction)| {
+                current_node = match instruction {
+                    0 => map[current_node].0,
+                    _ => map[current_node].1,
+                };
+                if current_node == "ZZZ" {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .unwrap() as u64
+    }
+
+    fn part2(instructions_map: &Self::Input) -> u64 {
+        let (instructions, map) = instructions_map;
+
+        let starting_nodes: Vec<&str> = map
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .copied()
+            .collect();
+
+        // It seems that the input is constructed in such a way that by finding the first node ending with 'Z', we have found a cycle.
+        lcm_all(starting_nodes.iter().map(|starting_node| {
+            let mut current_node = *starting_node;
+            instructions
+                .iter()
+                .cycle()
+                .enumerate()
+                .find_map(|(i, instruction)| {
+                    current_node = match instruction {
+                        0 => map[current_node].0,
+                        _ => map[current_node].1,
+                    };
+                    if current_node.ends_with('Z') {
+                        Some(i + 1)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap() as u64
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day08);
+
+    #[fixture]
+    fn data11() -> (
+        Vec<usize>,
+        HashMap<&'static str, (&'static str, &'static str)>,
+    ) {
+        let input = include_str!("test_input1.txt");
+        Day08::parse(input)
+    }
+
+    #[fixture]
+    fn data12() -> (
+        Vec<usize>,
+        HashMap<&'static str, (&'static str, &'static str)>,
+    ) {
+        let input = include_str!("test_input2.txt");
+        Day08::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> (
+        Vec<usize>,
+        HashMap<&'static str, (&'static str, &'static str)>,
+    ) {
+        let input = include_str!("test_input3.txt");
+        Day08::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test1(
+        data11: (
+            Vec<usize>,
+            HashMap<&'static str, (&'static str, &'static str)>,
+        ),
+    ) {
+        assert_eq!(Day08::part1(&data11), 2);
+    }
+
+    #[rstest]
+    fn part1_test2(
+        data12: (
+            Vec<usize>,
+            HashMap<&'static str, (&'static str, &'static str)>,
+        ),
+    ) {
+        assert_eq!(Day08::part1(&data12), 6);
+    }
+
+    #[rstest]
+    fn part2_test1(
+        data2: (
+            Vec<usize>,
+            HashMap<&'static str, (&'static str, &'static str)>,
+        ),
+    ) {
+        assert_eq!(Day08::part2(&data2), 6);
+    }
+
+    #[rstest]
+    #[case("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA,\n", 4)]
+    #[case("LR\n\nAAA = (BBB, CCC) and more\n", 3)]
+    #[case("LR\n", 2)]
+    fn malformed_input(#[case] input: &'static str, #[case] line: usize) {
+        assert_eq!(Day08::try_parse(input).unwrap_err().line, line);
+    }
+}
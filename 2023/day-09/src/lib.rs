@@ -0,0 +1,87 @@
+use aoc_utils::Solution;
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+pub struct Day09;
+
+#[aoc_macros::solution(2023, 9)]
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &'static str) -> Self::Input {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(sequences: &Self::Input) -> i64 {
+        sequences
+            .par_iter()
+            .map(|sequence| {
+                let mut nums = sequence.clone();
+                let mut last_nums: Vec<i64> = Vec::new();
+
+                while nums.iter().any(|distance| distance != &0) {
+                    last_nums.push(*nums.last().unwrap());
+                    nums = nums
+                        .iter()
+                        .tuple_windows()
+                        .map(|(left, right)| right - left)
+                        .collect();
+                }
+                last_nums.iter().sum::<i64>()
+            })
+            .sum()
+    }
+
+    fn part2(sequences: &Self::Input) -> i64 {
+        sequences
+            .par_iter()
+            .map(|sequence| {
+                let mut nums = sequence.clone();
+                let mut first_nums: Vec<i64> = Vec::new();
+
+                while nums.iter().any(|distance| distance != &0) {
+                    first_nums.push(*nums.first().unwrap());
+                    nums = nums
+                        .iter()
+                        .tuple_windows()
+                        .map(|(left, right)| right - left)
+                        .collect();
+                }
+
+                first_nums.iter().rev().fold(0, |acc, num| num - acc)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day09);
+
+    #[fixture]
+    fn data() -> Vec<Vec<i64>> {
+        let input = include_str!("test_input.txt");
+        Day09::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<Vec<i64>>) {
+        assert_eq!(Day09::part1(&data), 114);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<Vec<i64>>) {
+        assert_eq!(Day09::part2(&data), 2);
+    }
+}
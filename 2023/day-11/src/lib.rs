@@ -0,0 +1,104 @@
+use aoc_utils::Solution;
+use itertools::Itertools;
+
+pub struct Day11;
+
+#[aoc_macros::solution(2023, 11)]
+impl Solution for Day11 {
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(input: &'static str) -> Self::Input {
+        let image: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+        let empty_rows: Vec<usize> = (0..image.len())
+            .rev()
+            .filter(|y| image[*y].iter().all(|char| char == &'.'))
+            .collect();
+        let empty_cols: Vec<usize> = (0..image[0].len())
+            .rev()
+            .filter(|x| image.iter().map(|row| row[*x]).all(|char| char == '.'))
+            .collect();
+
+        let galaxies: Vec<_> =
+            image
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter().enumerate().filter_map(move |(x, char)| {
+                        if char == &'#' {
+                            Some((x, y))
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .collect();
+
+        galaxies
+            .iter()
+            .combinations(2)
+            .map(|galaxies| {
+                let (x1, y1) = *galaxies[0];
+                let (x2, y2) = *galaxies[1];
+
+                let base_distance =
+                    (((x2 as i64) - (x1 as i64)).abs() + ((y2 as i64) - (y1 as i64)).abs()) as u64;
+                let crossings = (empty_rows
+                    .iter()
+                    .filter(|row| (y1.min(y2) + 1..y1.max(y2)).contains(row))
+                    .count()
+                    + empty_cols
+                        .iter()
+                        .filter(|row| (x1.min(x2) + 1..x1.max(x2)).contains(row))
+                        .count()) as u64;
+
+                (base_distance, crossings)
+            })
+            .collect()
+    }
+
+    fn part1(image_galaxies_distances: &Self::Input) -> u64 {
+        expand(2, image_galaxies_distances).iter().sum()
+    }
+
+    fn part2(image_galaxies_distances: &Self::Input) -> u64 {
+        expand(1_000_000, image_galaxies_distances).iter().sum()
+    }
+}
+
+fn expand(expansion_factor: u64, distances: &[(u64, u64)]) -> Vec<u64> {
+    distances
+        .iter()
+        .map(|(base_distance, crossings)| base_distance + crossings * expansion_factor - crossings)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day11);
+
+    #[fixture]
+    fn data() -> Vec<(u64, u64)> {
+        let input = include_str!("test_input.txt");
+        Day11::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<(u64, u64)>) {
+        assert_eq!(Day11::part1(&data), 374);
+    }
+
+    #[rstest]
+    fn part2_test1(data: Vec<(u64, u64)>) {
+        assert_eq!(expand(10, &data).iter().sum::<u64>(), 1030);
+    }
+
+    #[rstest]
+    fn part2_test2(data: Vec<(u64, u64)>) {
+        assert_eq!(expand(100, &data).iter().sum::<u64>(), 8410);
+    }
+}
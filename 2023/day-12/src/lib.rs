@@ -0,0 +1,170 @@
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+#[derive(Copy, Clone)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl Spring {
+    fn is_operational(self) -> bool {
+        matches!(self, Spring::Operational | Spring::Unknown)
+    }
+
+    fn is_damaged(self) -> bool {
+        matches!(self, Spring::Damaged | Spring::Unknown)
+    }
+}
+
+pub struct Day12;
+
+#[aoc_macros::solution(2023, 12)]
+impl Solution for Day12 {
+    type Input = Vec<(Vec<Spring>, Vec<bool>)>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let parts = line.split_once(' ').ok_or_else(|| {
+                    ParseError::at(input, line, "expected springs and group sizes")
+                })?;
+
+                let springs = parts
+                    .0
+                    .char_indices()
+                    .map(|(i, c)| match c {
+                        '.' => Ok(Spring::Operational),
+                        '#' => Ok(Spring::Damaged),
+                        '?' => Ok(Spring::Unknown),
+                        _ => Err(ParseError::at(
+                            input,
+                            &parts.0[i..],
+                            format!("unknown spring {c:?}"),
+                        )),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                let group_sizes = parts
+                    .1
+                    .split(',')
+                    .map(|num| parse_part(input, num))
+                    .collect::<Result<Vec<usize>, _>>()?;
+                let broken = group_sizes
+                    .into_iter()
+                    .map(|group_size| std::iter::repeat(true).take(group_size))
+                    .intersperse(std::iter::repeat(false).take(1))
+                    .flatten()
+                    .collect();
+
+                Ok((springs, broken))
+            })
+            .collect()
+    }
+
+    fn part1(springs: &Self::Input) -> u64 {
+        count_possible_arrangements(&unfold_and_prepare(springs, 1))
+    }
+
+    fn part2(springs: &Self::Input) -> u64 {
+        count_possible_arrangements(&unfold_and_prepare(springs, 5))
+    }
+}
+
+fn unfold_and_prepare(
+    springs: &Vec<(Vec<Spring>, Vec<bool>)>,
+    folds: usize,
+) -> Vec<(Vec<Spring>, Vec<bool>)> {
+    springs
+        .par_iter()
+        .map(|(springs, broken)| {
+            let unfolded_springs = std::iter::once(Spring::Operational)
+                .chain(
+                    std::iter::repeat(springs.iter().copied())
+                        .take(folds)
+                        .intersperse([Spring::Unknown].iter().copied())
+                        .flatten(),
+                )
+                .chain(std::iter::once(Spring::Operational))
+                .collect();
+
+            let unfolded_broken = std::iter::once(false)
+                .chain(
+                    std::iter::repeat(broken.iter().copied())
+                        .take(folds)
+                        .intersperse([false].iter().copied())
+                        .flatten(),
+                )
+                .chain(std::iter::once(false))
+                .collect();
+
+            (unfolded_springs, unfolded_broken)
+        })
+        .collect()
+}
+
+fn count_possible_arrangements(springs: &Vec<(Vec<Spring>, Vec<bool>)>) -> u64 {
+    springs
+        .par_iter()
+        .map(|(springs, broken)| {
+            let n = springs.len();
+            let m = broken.len();
+
+            // [i][j] represents the number of valid configurations for springs[i..n] and broken[j..m]
+            let mut possible_arrangements = vec![vec![0u64; m + 1]; n + 1];
+            possible_arrangements[n][m] = 1;
+
+            for i in (0..n).rev() {
+                let spring = &springs[i];
+
+                for j in (m.saturating_sub(n - i)..m).rev() {
+                    possible_arrangements[i][j] = if spring.is_damaged() && broken[j] {
+                        // The current spring is damaged, and the broken sequence expects a damaged spring.
+                        // Move to the next spring and the next expectation.
+                        possible_arrangements[i + 1][j + 1]
+                    } else if spring.is_operational() && !broken[j] {
+                        // The current spring is operational, and the broken sequence expects an operational spring.
+                        // There are two options:
+                        // - The next spring is part of the same group (operational).
+                        // - The next spring starts a new group (broken).
+                        possible_arrangements[i + 1][j + 1] + possible_arrangements[i + 1][j]
+                    } else {
+                        // The current spring and the expectation do not match.
+                        0
+                    };
+                }
+            }
+
+            possible_arrangements[0][0]
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day12);
+
+    #[fixture]
+    fn data() -> Vec<(Vec<Spring>, Vec<bool>)> {
+        let input = include_str!("test_input.txt");
+        Day12::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<(Vec<Spring>, Vec<bool>)>) {
+        assert_eq!(Day12::part1(&data), 21);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<(Vec<Spring>, Vec<bool>)>) {
+        assert_eq!(Day12::part2(&data), 525_152);
+    }
+}
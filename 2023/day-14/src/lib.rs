@@ -0,0 +1,135 @@
+use aoc_utils::cycle::state_after;
+use aoc_utils::Solution;
+
+pub struct Day14;
+
+#[aoc_macros::solution(2023, 14)]
+impl Solution for Day14 {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &'static str) -> Self::Input {
+        input.lines().map(|line| line.chars().collect()).collect()
+    }
+
+    fn part1(platform: &Self::Input) -> u64 {
+        let mut platform = platform.clone();
+        tilt_north(&mut platform);
+        calculate_load_on_north_beam(&platform)
+    }
+
+    fn part2(platform: &Self::Input) -> u64 {
+        let platform = state_after(platform.clone(), 1_000_000_000, |platform| {
+            let mut platform = platform.clone();
+            tilt_north(&mut platform);
+            tilt_west(&mut platform);
+            tilt_south(&mut platform);
+            tilt_east(&mut platform);
+            platform
+        });
+
+        calculate_load_on_north_beam(&platform)
+    }
+}
+
+fn tilt_north(platform: &mut [Vec<char>]) {
+    for x in 0..platform[0].len() {
+        let mut next_y = 0;
+        for y in 0..platform.len() {
+            match platform[y][x] {
+                '#' => next_y = y + 1,
+                'O' => {
+                    platform[y][x] = '.';
+                    platform[next_y][x] = 'O';
+                    next_y += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn tilt_west(platform: &mut [Vec<char>]) {
+    for y in 0..platform.len() {
+        let mut next_x = 0;
+        for x in 0..platform[0].len() {
+            match platform[y][x] {
+                '#' => next_x = x + 1,
+                'O' => {
+                    platform[y][x] = '.';
+                    platform[y][next_x] = 'O';
+                    next_x += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn tilt_south(platform: &mut [Vec<char>]) {
+    for x in 0..platform[0].len() {
+        let mut next_y = platform.len() - 1;
+        for y in (0..platform.len()).rev() {
+            match platform[y][x] {
+                '#' => next_y = y.saturating_sub(1),
+                'O' => {
+                    platform[y][x] = '.';
+                    platform[next_y][x] = 'O';
+                    next_y = next_y.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn tilt_east(platform: &mut [Vec<char>]) {
+    for y in 0..platform.len() {
+        let mut next_x = platform[0].len() - 1;
+        for x in (0..platform[0].len()).rev() {
+            match platform[y][x] {
+                '#' => next_x = x.saturating_sub(1),
+                'O' => {
+                    platform[y][x] = '.';
+                    platform[y][next_x] = 'O';
+                    next_x = next_x.saturating_sub(1);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn calculate_load_on_north_beam(platform: &[Vec<char>]) -> u64 {
+    platform
+        .iter()
+        .zip((1..=platform.len()).rev())
+        .map(|(row, load_caused_by_one)| {
+            row.iter().filter(|tile| **tile == 'O').count() * load_caused_by_one
+        })
+        .sum::<usize>() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day14);
+
+    #[fixture]
+    fn data() -> Vec<Vec<char>> {
+        let input = include_str!("test_input.txt");
+        Day14::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Vec<Vec<char>>) {
+        assert_eq!(Day14::part1(&data), 136);
+    }
+
+    #[rstest]
+    fn part2_test(data: Vec<Vec<char>>) {
+        assert_eq!(Day14::part2(&data), 64);
+    }
+}
@@ -0,0 +1,171 @@
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Direction {
+    North = 3,
+    West = 2,
+    South = 1,
+    East = 0,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | '3' => Ok(Direction::North),
+            'D' | '1' => Ok(Direction::South),
+            'L' | '2' => Ok(Direction::West),
+            'R' | '0' => Ok(Direction::East),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Instruction {
+    direction1: Direction,
+    meters1: i64,
+    direction2: Direction,
+    meters2: i64,
+}
+
+pub struct Day18;
+
+#[aoc_macros::solution(2023, 18)]
+impl Solution for Day18 {
+    type Input = (Vec<(i64, i64)>, Vec<(i64, i64)>);
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(
+            r"^(?<direction1>[UDLR]) (?<meters1>\d+) \(#(?<meters2>[0-9a-fA-F]{5})(?<direction2>[0-3])\)$",
+        )
+        .unwrap();
+        let instructions: Vec<Instruction> = input
+            .trim_end()
+            .lines()
+            .map(|line| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| ParseError::at(input, line, "expected `R 6 (#70c710)`"))?;
+                let direction1 = caps["direction1"]
+                    .chars()
+                    .next()
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let meters1 = parse_part(input, caps.name("meters1").unwrap().as_str())?;
+                let direction2 = caps["direction2"]
+                    .chars()
+                    .next()
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                let meters2 = i64::from_str_radix(&caps["meters2"], 16).unwrap();
+                Ok(Instruction {
+                    direction1,
+                    meters1,
+                    direction2,
+                    meters2,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        if instructions.is_empty() {
+            return Err(ParseError::new(
+                input,
+                0,
+                "expected at least one instruction",
+            ));
+        }
+
+        let mut coords1: Vec<(i64, i64)> = Vec::with_capacity(instructions.len());
+        let mut coords2: Vec<(i64, i64)> = Vec::with_capacity(instructions.len());
+        let mut current1 = (0, 0);
+        let mut current2 = (0, 0);
+        coords1.push(current1);
+        coords2.push(current2);
+
+        for instruction in instructions.iter().take(instructions.len() - 1) {
+            let meters = instruction.meters1;
+            match instruction.direction1 {
+                Direction::North => current1.1 -= meters,
+                Direction::West => current1.0 -= meters,
+                Direction::South => current1.1 += meters,
+                Direction::East => current1.0 += meters,
+            };
+            coords1.push(current1);
+            let meters = instruction.meters2;
+            match instruction.direction2 {
+                Direction::North => current2.1 -= meters,
+                Direction::West => current2.0 -= meters,
+                Direction::South => current2.1 += meters,
+                Direction::East => current2.0 += meters,
+            };
+            coords2.push(current2);
+        }
+
+        Ok((coords1, coords2))
+    }
+
+    fn part1(coords: &Self::Input) -> i64 {
+        calculate_area(&coords.0)
+    }
+
+    fn part2(coords: &Self::Input) -> i64 {
+        calculate_area(&coords.1)
+    }
+}
+
+/// Looking for i (inner area) + b (border)
+/// Using:
+/// [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem)
+/// [Shoelace formula](https://en.wikipedia.org/wiki/Shoelace_formula)
+///
+///     A = i + b/2 - 1
+/// <=> i = A - b/2 + 1
+/// Adding b:
+///  => i + b = A + b/2 + 1
+fn calculate_area(coords: &[(i64, i64)]) -> i64 {
+    let coord_pairs: Vec<_> = coords.iter().zip(coords.iter().cycle().skip(1)).collect();
+
+    let b = coord_pairs
+        .iter()
+        .map(|((x1, y1), (x2, y2))| (x1 - x2).abs() + (y1 - y2).abs())
+        .sum::<i64>();
+
+    let i = coord_pairs
+        .iter()
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<i64>()
+        .abs()
+        / 2;
+
+    i + b / 2 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day18);
+
+    #[fixture]
+    fn data() -> (Vec<(i64, i64)>, Vec<(i64, i64)>) {
+        let input = include_str!("test_input.txt");
+        Day18::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: (Vec<(i64, i64)>, Vec<(i64, i64)>)) {
+        assert_eq!(Day18::part1(&data), 62);
+    }
+
+    #[rstest]
+    fn part2_test(data: (Vec<(i64, i64)>, Vec<(i64, i64)>)) {
+        assert_eq!(Day18::part2(&data), 952_408_144_115);
+    }
+}
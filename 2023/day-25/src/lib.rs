@@ -0,0 +1,49 @@
+use aoc_utils::graph::Graph;
+use aoc_utils::{ParseError, Solution};
+
+pub struct Day25;
+
+#[aoc_macros::solution(2023, 25)]
+impl Solution for Day25 {
+    type Input = Graph<&'static str>;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let mut graph = Graph::undirected();
+
+        for line in input.lines() {
+            let (source, children) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(input, line, "expected `jqt: rhn xhk nvd`"))?;
+            for child in children.split(' ') {
+                graph.add_edge(source, child);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    fn part1(graph: &Self::Input) -> usize {
+        let (_cut_size, partition) = graph.min_cut();
+        partition.len() * (graph.len() - partition.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day25);
+
+    #[fixture]
+    fn data() -> Graph<&'static str> {
+        let input = include_str!("test_input.txt");
+        Day25::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: Graph<&'static str>) {
+        assert_eq!(Day25::part1(&data), 54);
+    }
+}
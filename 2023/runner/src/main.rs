@@ -0,0 +1,34 @@
+use aoc_utils::runner::Day;
+use std::process::ExitCode;
+
+const DAYS: &[Day] = &[
+    Day::of::<day_01::Day01>(),
+    Day::of::<day_02::Day02>(),
+    Day::of::<day_03::Day03>(),
+    Day::of::<day_04::Day04>(),
+    Day::of::<day_05::Day05>(),
+    Day::of::<day_06::Day06>(),
+    Day::of::<day_07::Day07>(),
+    Day::of::<day_08::Day08>(),
+    Day::of::<day_09::Day09>(),
+    Day::of::<day_10::Day10>(),
+    Day::of::<day_11::Day11>(),
+    Day::of::<day_12::Day12>(),
+    Day::of::<day_13::Day13>(),
+    Day::of::<day_14::Day14>(),
+    Day::of::<day_15::Day15>(),
+    Day::of::<day_16::Day16>(),
+    Day::of::<day_17::Day17>(),
+    Day::of::<day_18::Day18>(),
+    Day::of::<day_19::Day19>(),
+    Day::of::<day_20::Day20>(),
+    Day::of::<day_21::Day21>(),
+    Day::of::<day_22::Day22>(),
+    Day::of::<day_23::Day23>(),
+    Day::of::<day_24::Day24>(),
+    Day::of::<day_25::Day25>(),
+];
+
+fn main() -> ExitCode {
+    aoc_utils::runner::main(DAYS)
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+use winnow::ascii::space1;
+use winnow::combinator::separated_pair;
+use winnow::{ModalResult, Parser};
+
+use aoc_utils::parse::{lines, number, parse_all};
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = Vec<(u32, u32)>;
+type ProcessedInput = (Vec<u32>, Vec<u32>);
+
+fn parse_input(input: &mut &str) -> ModalResult<ParsedInput> {
+    lines(separated_pair(number, space1, number)).parse_next(input)
+}
+
+pub struct Day01;
+
+#[aoc_macros::solution(2024, 1)]
+impl Solution for Day01 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        Ok(parse_all(parse_input, input)?.into_iter().unzip())
+    }
+
+    fn part1(lists: &Self::Input) -> u32 {
+        let mut list0 = lists.0.clone();
+        let mut list1 = lists.1.clone();
+        list0.sort_unstable();
+        list1.sort_unstable();
+
+        list0
+            .into_iter()
+            .zip(list1)
+            .map(|(a, b)| a.abs_diff(b))
+            .sum()
+    }
+
+    fn part2(lists: &Self::Input) -> u32 {
+        let mut frequency_map: HashMap<u32, u32> = HashMap::new();
+
+        for &num in &lists.1 {
+            *frequency_map.entry(num).or_insert(0) += 1;
+        }
+
+        lists
+            .0
+            .iter()
+            .map(|&num| num * *frequency_map.entry(num).or_insert(0))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day01);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day01::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day01::part1(&data), 11);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day01::part2(&data), 31);
+    }
+}
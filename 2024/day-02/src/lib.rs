@@ -0,0 +1,79 @@
+use aoc_utils::parse::{lines, numbers, parse_all};
+use aoc_utils::{ParseError, Solution};
+use itertools::Itertools;
+use winnow::{ModalResult, Parser};
+
+type ParsedInput = Vec<Vec<u32>>;
+type ProcessedInput = Vec<Vec<u32>>;
+
+fn parse_input(input: &mut &str) -> ModalResult<ParsedInput> {
+    lines(numbers).parse_next(input)
+}
+
+pub struct Day02;
+
+#[aoc_macros::solution(2024, 2)]
+impl Solution for Day02 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(parse_input, input)
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter(|&report| is_valid_report(report))
+            .count()
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter(|&report| {
+                (0..report.len()).any(|skip| {
+                    is_valid_report(
+                        &report
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| *i != skip)
+                            .map(|(_, &v)| v)
+                            .collect::<Vec<_>>(),
+                    )
+                })
+            })
+            .count()
+    }
+}
+
+fn is_valid_report(report: &[u32]) -> bool {
+    report.iter().tuple_windows().all(|(a, &b)| {
+        (1..=3).contains(&a.abs_diff(b))
+            && (report.iter().is_sorted() || report.iter().rev().is_sorted())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day02);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day02::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day02::part1(&data), 2);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day02::part2(&data), 4);
+    }
+}
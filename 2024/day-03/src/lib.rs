@@ -0,0 +1,107 @@
+use aoc_utils::Solution;
+use regex::Regex;
+
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+type ParsedInput = Vec<Instruction>;
+type ProcessedInput = Vec<Instruction>;
+
+fn parse_input(input: &'static str) -> ParsedInput {
+    let re = Regex::new(
+        r"(?<mul>(mul\((?<x>\d{1,3}),(?<y>\d{1,3})\)))|(?<do>do\(\))|(?<dont>don't\(\))",
+    )
+    .unwrap();
+
+    re.captures_iter(input)
+        .map(|caps| {
+            if caps.name("mul").is_some() {
+                Instruction::Mul(caps["x"].parse().unwrap(), caps["y"].parse().unwrap())
+            } else if caps.name("do").is_some() {
+                Instruction::Do
+            } else {
+                Instruction::Dont
+            }
+        })
+        .collect()
+}
+
+pub struct Day03;
+
+#[aoc_macros::solution(2024, 3)]
+impl Solution for Day03 {
+    type Input = ProcessedInput;
+
+    fn parse(input: &'static str) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(instructions: &Self::Input) -> u32 {
+        instructions
+            .iter()
+            .filter_map(|instr| match instr {
+                Instruction::Mul(x, y) => Some(x * y),
+                Instruction::Do | Instruction::Dont => None,
+            })
+            .sum()
+    }
+
+    fn part2(instructions: &Self::Input) -> u32 {
+        let mut enabled = true;
+
+        instructions
+            .iter()
+            .filter_map(|instr| match instr {
+                Instruction::Mul(x, y) => {
+                    if enabled {
+                        Some(x * y)
+                    } else {
+                        None
+                    }
+                }
+                Instruction::Do => {
+                    enabled = true;
+                    None
+                }
+                Instruction::Dont => {
+                    enabled = false;
+                    None
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day03);
+
+    #[fixture]
+    fn data1() -> ProcessedInput {
+        let input = include_str!("test_input1.txt");
+        Day03::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> ProcessedInput {
+        let input = include_str!("test_input2.txt");
+        Day03::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data1: ProcessedInput) {
+        assert_eq!(Day03::part1(&data1), 161);
+    }
+
+    #[rstest]
+    fn part2_test(data2: ProcessedInput) {
+        assert_eq!(Day03::part2(&data2), 48);
+    }
+}
@@ -0,0 +1,82 @@
+use aoc_utils::parse::{char_grid, parse_all};
+use aoc_utils::{Grid, ParseError, Solution};
+
+type ProcessedInput = Grid<char>;
+
+pub struct Day04;
+
+#[aoc_macros::solution(2024, 4)]
+impl Solution for Day04 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(char_grid, input)
+    }
+
+    fn part1(grid: &Self::Input) -> u32 {
+        grid.positions_of(&'X')
+            .map(|position| xmas_starts(grid, position))
+            .sum()
+    }
+
+    fn part2(grid: &Self::Input) -> u32 {
+        grid.positions_of(&'A')
+            .filter(|&position| is_x_mas_middle(grid, position))
+            .count() as u32
+    }
+}
+
+fn xmas_starts(grid: &ProcessedInput, position: (usize, usize)) -> u32 {
+    let directions = [
+        (0, -1),  // Up
+        (1, -1),  // Up-Right
+        (1, 0),   // Right
+        (1, 1),   // Down-Right
+        (0, 1),   // Down
+        (-1, 1),  // Down-Left
+        (-1, 0),  // Left
+        (-1, -1), // Up-Left
+    ];
+
+    directions
+        .into_iter()
+        .filter(|&direction| {
+            grid.ray(position, direction)
+                .take(4)
+                .eq(&['X', 'M', 'A', 'S'])
+        })
+        .count() as u32
+}
+
+fn is_x_mas_middle(grid: &ProcessedInput, (x, y): (usize, usize)) -> bool {
+    let (x, y) = (x as isize, y as isize);
+    let corner = |dx, dy| grid.get_signed((x + dx, y + dy)).copied();
+    let is_mas = |a, b| matches!((a, b), (Some('M'), Some('S')) | (Some('S'), Some('M')));
+
+    is_mas(corner(-1, -1), corner(1, 1)) && is_mas(corner(1, -1), corner(-1, 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day04);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day04::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day04::part1(&data), 18);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day04::part2(&data), 9);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::{HashMap, HashSet};
+use winnow::ascii::line_ending;
+use winnow::combinator::separated_pair;
+use winnow::{ModalResult, Parser};
+
+use aoc_utils::parse::{lines, number, numbers, parse_all};
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = (Vec<(u32, u32)>, Vec<Vec<u32>>);
+type ProcessedInput = (Vec<Vec<u32>>, HashMap<u32, HashSet<u32>>);
+
+fn parse_input(input: &mut &str) -> ModalResult<ParsedInput> {
+    separated_pair(
+        lines(separated_pair(number, '|', number)),
+        (line_ending, line_ending),
+        lines(numbers),
+    )
+    .parse_next(input)
+}
+
+pub struct Day05;
+
+#[aoc_macros::solution(2024, 5)]
+impl Solution for Day05 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let parsed_input = parse_all(parse_input, input)?;
+
+        let mut after_map: HashMap<u32, HashSet<u32>> = HashMap::new();
+        for (before, after) in &parsed_input.0 {
+            after_map.entry(*after).or_default().insert(*before);
+        }
+
+        Ok((parsed_input.1, after_map))
+    }
+
+    fn part1((updates, after_map): &Self::Input) -> u32 {
+        updates
+            .iter()
+            .filter(|&update| is_sorted(update, after_map))
+            .map(|update| update[update.len() / 2])
+            .sum()
+    }
+
+    fn part2((updates, after_map): &Self::Input) -> u32 {
+        updates
+            .iter()
+            .filter(|&update| !is_sorted(update, after_map))
+            .cloned()
+            .map(|mut update| {
+                sort(&mut update, after_map);
+                update[update.len() / 2]
+            })
+            .sum()
+    }
+}
+
+fn is_sorted(update: &[u32], after_map: &HashMap<u32, HashSet<u32>>) -> bool {
+    let mut after_pages = update[1..update.len()]
+        .iter()
+        .copied()
+        .collect::<HashSet<_>>();
+
+    for page in update {
+        if let Some(before) = after_map.get(page) {
+            if !after_pages.is_disjoint(before) {
+                return false;
+            }
+        }
+        after_pages.remove(page);
+    }
+
+    true
+}
+
+fn sort(update: &mut [u32], after_map: &HashMap<u32, HashSet<u32>>) {
+    let pages = update.iter().copied().collect::<HashSet<_>>();
+
+    let mut filtered_after_map: HashMap<u32, HashSet<u32>> = HashMap::new();
+    for page in update.iter() {
+        if let Some(after) = after_map.get(page) {
+            filtered_after_map.insert(*page, after.intersection(&pages).copied().collect());
+        }
+    }
+
+    update.sort_by_key(|&page| {
+        std::cmp::Reverse(filtered_after_map.get(&page).map_or(0, HashSet::len))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day05);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day05::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day05::part1(&data), 143);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day05::part2(&data), 123);
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_utils::{Dir4, Point2, Solution};
+
+type ParsedInput = Vec<Vec<char>>;
+type ProcessedInput = (Vec<Vec<Field>>, Agent);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    field_type: FieldType,
+    visited: [bool; 4],
+}
+
+impl Field {
+    fn new(field_type: FieldType) -> Field {
+        Self {
+            field_type,
+            visited: [false; 4],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldType {
+    Border,
+    Floor,
+    Object,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Agent {
+    position: Point2<usize>,
+    direction: Dir4,
+}
+
+enum AgentError {
+    AlreadyOnBorder,
+    EncounteredObject,
+    SteppedOnBorder,
+    EncounteredLoop,
+}
+
+impl Agent {
+    fn new(x: usize, y: usize, direction: Dir4) -> Self {
+        Self {
+            position: Point2::new(x, y),
+            direction,
+        }
+    }
+
+    fn rotate(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    fn step(&mut self, map: &mut [Vec<Field>]) -> Result<(), AgentError> {
+        let Point2 { x, y } = self.position;
+        if map[y][x].field_type == FieldType::Border {
+            return Err(AgentError::AlreadyOnBorder);
+        }
+
+        // The border keeps the agent from stepping below zero.
+        let next = self.position.checked_step(self.direction).unwrap();
+        let field = &mut map[next.y][next.x];
+        if field.field_type == FieldType::Object {
+            return Err(AgentError::EncounteredObject);
+        }
+
+        self.position = next;
+
+        if field.visited[self.direction as usize] {
+            Err(AgentError::EncounteredLoop)
+        } else if field.field_type == FieldType::Border {
+            Err(AgentError::SteppedOnBorder)
+        } else {
+            field.visited[self.direction as usize] = true;
+            Ok(())
+        }
+    }
+}
+
+fn parse_input(input: &'static str) -> ParsedInput {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect()
+}
+
+pub struct Day06;
+
+#[aoc_macros::solution(2024, 6)]
+impl Solution for Day06 {
+    type Input = ProcessedInput;
+
+    fn parse(input: &'static str) -> Self::Input {
+        let map = parse_input(input);
+
+        let rows = map.len();
+        let cols = if rows > 0 { map[0].len() } else { 0 };
+
+        let new_rows = rows + 2;
+        let new_cols = cols + 2;
+
+        let mut new_map = vec![vec![Field::new(FieldType::Border); new_cols]; new_rows];
+        let mut agent = Agent::new(0, 0, Dir4::Up);
+
+        for y in 0..rows {
+            for x in 0..cols {
+                match map[y][x] {
+                    '.' => new_map[y + 1][x + 1] = Field::new(FieldType::Floor),
+                    '#' => new_map[y + 1][x + 1] = Field::new(FieldType::Object),
+                    c @ ('^' | '>' | 'v' | '<') => {
+                        let direction = Dir4::from_arrow(c).unwrap();
+                        new_map[y + 1][x + 1] = Field::new(FieldType::Floor);
+                        new_map[y + 1][x + 1].visited[direction as usize] = true;
+                        agent = Agent::new(x + 1, y + 1, direction);
+                    }
+                    _ => panic!("Invalid character in input"),
+                }
+            }
+        }
+
+        (new_map, agent)
+    }
+
+    fn part1((map, agent): &Self::Input) -> usize {
+        let mut map = map.clone();
+        let mut agent = *agent;
+
+        loop {
+            match agent.step(&mut map) {
+                Ok(()) => {}
+                Err(AgentError::AlreadyOnBorder) => panic!("Shouldn't be standing on border"),
+                Err(AgentError::EncounteredObject) => agent.rotate(),
+                Err(AgentError::SteppedOnBorder) => break,
+                Err(AgentError::EncounteredLoop) => panic!("Shouldn't encounter loop"),
+            }
+        }
+
+        map.iter()
+            .flatten()
+            .filter(|field| field.visited.iter().any(|&visited| visited))
+            .count()
+    }
+
+    fn part2((map, agent): &Self::Input) -> usize {
+        let mut loops = 0;
+
+        for y in 1..map.len() - 1 {
+            for x in 1..map[0].len() - 1 {
+                let mut map = map.clone();
+                let mut agent = *agent;
+
+                map[y][x] = Field::new(FieldType::Object);
+
+                loop {
+                    match agent.step(&mut map) {
+                        Ok(()) => {}
+                        Err(AgentError::AlreadyOnBorder) => {
+                            panic!("Shouldn't be standing on border")
+                        }
+                        Err(AgentError::EncounteredObject) => agent.rotate(),
+                        Err(AgentError::SteppedOnBorder) => break,
+                        Err(AgentError::EncounteredLoop) => {
+                            loops += 1;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        loops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day06);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day06::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day06::part1(&data), 41);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day06::part2(&data), 6);
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_utils::parse::{key_values, number, numbers, parse_all};
+use aoc_utils::{ParseError, Solution};
+use winnow::{ModalResult, Parser};
+
+type ParsedInput = Vec<(u64, Vec<u64>)>;
+type ProcessedInput = Vec<(u64, Vec<u64>)>;
+
+fn parse_input(input: &mut &str) -> ModalResult<ParsedInput> {
+    key_values(number, numbers).parse_next(input)
+}
+
+pub struct Day07;
+
+#[aoc_macros::solution(2024, 7)]
+impl Solution for Day07 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(parse_input, input)
+    }
+
+    fn part1(equations: &Self::Input) -> u64 {
+        equations
+            .iter()
+            .filter(|(result, components)| {
+                let mut possible_values = vec![components[0]];
+
+                for component in &components[1..] {
+                    let mut next_possible_values = Vec::new();
+
+                    for value in possible_values {
+                        next_possible_values.push(value + *component);
+                        next_possible_values.push(value * *component);
+                    }
+
+                    possible_values = next_possible_values;
+                }
+
+                possible_values.iter().any(|value| value == result)
+            })
+            .map(|equation| equation.0)
+            .sum()
+    }
+
+    fn part2(equations: &Self::Input) -> u64 {
+        equations
+            .iter()
+            .filter(|(result, components)| {
+                let mut possible_values = vec![components[0]];
+
+                for component in &components[1..] {
+                    let mut next_possible_values = Vec::new();
+
+                    for value in possible_values {
+                        next_possible_values.push(value + *component);
+                        next_possible_values.push(value * *component);
+                        next_possible_values.push(concat(value, *component));
+                    }
+
+                    possible_values = next_possible_values;
+                }
+
+                possible_values.iter().any(|value| value == result)
+            })
+            .map(|equation| equation.0)
+            .sum()
+    }
+}
+
+fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(b.ilog10() + 1) + b
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day07);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day07::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day07::part1(&data), 3749);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day07::part2(&data), 11387);
+    }
+}
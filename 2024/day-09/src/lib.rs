@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = Vec<u64>;
+type ProcessedInput = Vec<(Option<u64>, u64)>;
+
+fn parse_input(input: &'static str) -> Result<ParsedInput, ParseError> {
+    let disk_map = input.trim();
+    disk_map
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(u64::from)
+                .ok_or_else(|| ParseError::at(input, &disk_map[i..], "expected a digit"))
+        })
+        .collect()
+}
+
+pub struct Day09;
+
+#[aoc_macros::solution(2024, 9)]
+impl Solution for Day09 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let map = parse_input(input)?;
+
+        let mut enhanced_map = Vec::new();
+
+        let mut data = true;
+        let mut next_id = 0;
+
+        for value in map {
+            if data {
+                enhanced_map.push((Some(next_id), value));
+                next_id += 1;
+            } else {
+                enhanced_map.push((None, value));
+            }
+
+            data = !data;
+        }
+
+        Ok(enhanced_map)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        let mut blocks = map_to_blocks(map);
+
+        let mut next_empty = blocks
+            .iter()
+            .enumerate()
+            .find(|(_i, block)| block.is_none())
+            .map(|(i, _block)| i)
+            .unwrap();
+        let mut next_full = blocks
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, block)| block.map(|_| i))
+            .unwrap();
+
+        while next_empty < next_full {
+            blocks.swap(next_empty, next_full);
+
+            next_empty += 1;
+            while blocks[next_empty].is_some() {
+                next_empty += 1;
+            }
+
+            next_full -= 1;
+            while blocks[next_full].is_none() {
+                next_full -= 1;
+            }
+        }
+
+        blocks_to_checksum(&blocks)
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        let mut map = map.clone();
+
+        let mut index = map.len() - 1;
+        while index > 0 {
+            let block = map[index];
+            let (value, amount) = block;
+
+            if value.is_some() {
+                for new_index in 0..index {
+                    let (new_value, new_amount) = map[new_index];
+                    if new_value.is_none() {
+                        match amount.cmp(&new_amount) {
+                            Ordering::Less => {
+                                map[new_index].1 = new_amount - amount;
+                                map[index].0 = None;
+                                map.insert(new_index, block);
+                                break;
+                            }
+                            Ordering::Equal => {
+                                map.swap(index, new_index);
+                                break;
+                            }
+                            Ordering::Greater => {}
+                        }
+                    }
+                }
+            }
+
+            index -= 1;
+        }
+
+        map_to_checksum(&map)
+    }
+}
+
+fn map_to_blocks(map: &Vec<(Option<u64>, u64)>) -> Vec<Option<u64>> {
+    let mut blocks = Vec::new();
+
+    for (value, amount) in map {
+        for _i in 0..*amount {
+            blocks.push(*value);
+        }
+    }
+
+    blocks
+}
+
+fn blocks_to_checksum(blocks: &[Option<u64>]) -> u64 {
+    blocks
+        .iter()
+        .map(|block| block.unwrap_or(0))
+        .enumerate()
+        .map(|(i, value)| i as u64 * value)
+        .sum()
+}
+
+fn map_to_checksum(map: &[(Option<u64>, u64)]) -> u64 {
+    let mut checksum = 0;
+    let mut index = 0;
+
+    for (value, amount) in map {
+        let value = value.unwrap_or(0);
+        for _ in 0..*amount {
+            checksum += index * value;
+            index += 1;
+        }
+    }
+
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day09);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day09::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day09::part1(&data), 1928);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day09::part2(&data), 2858);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = Vec<Vec<u32>>;
+type ProcessedInput = (usize, usize);
+
+fn parse_input(input: &'static str) -> Result<ParsedInput, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, ch)| {
+                    ch.to_digit(10)
+                        .ok_or_else(|| ParseError::at(input, &line[i..], "expected a height"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+enum VisitState {
+    Pre,
+    Post,
+}
+
+fn run_dfs(map: &[Vec<u32>], trailheads: &[(usize, usize)]) -> ProcessedInput {
+    trailheads
+        .iter()
+        .map(|trailhead| {
+            let mut visited = vec![vec![false; map[0].len()]; map.len()];
+
+            let mut trailtails = HashSet::new();
+
+            let mut distinct_trails = 0;
+
+            let mut stack = vec![(*trailhead, VisitState::Pre)];
+            visited[trailhead.1][trailhead.0] = true;
+
+            while let Some((pos, state)) = stack.pop() {
+                match state {
+                    VisitState::Pre => {
+                        stack.push((pos, VisitState::Post));
+
+                        let height = map[pos.1][pos.0];
+
+                        let next = vec![
+                            (pos.0, pos.1 - 1),
+                            (pos.0 + 1, pos.1),
+                            (pos.0, pos.1 + 1),
+                            (pos.0 - 1, pos.1),
+                        ];
+
+                        for next_pos in next {
+                            if !visited[next_pos.1][next_pos.0] {
+                                let new_height = map[next_pos.1][next_pos.0];
+                                if new_height == height + 1 {
+                                    if new_height == 9 {
+                                        trailtails.insert(next_pos);
+                                        distinct_trails += 1;
+                                    } else {
+                                        stack.push((next_pos, VisitState::Pre));
+                                        visited[next_pos.1][next_pos.0] = true;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    VisitState::Post => {
+                        visited[pos.1][pos.0] = false;
+                    }
+                }
+            }
+
+            (trailtails.len(), distinct_trails)
+        })
+        .fold((0, 0), |acc, x| (acc.0 + x.0, acc.1 + x.1))
+}
+
+pub struct Day10;
+
+#[aoc_macros::solution(2024, 10)]
+impl Solution for Day10 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let map = parse_input(input)?;
+
+        let rows = map.len();
+        let cols = if rows > 0 { map[0].len() } else { 0 };
+
+        let new_rows = rows + 2;
+        let new_cols = cols + 2;
+
+        let mut enhanced_map = vec![vec![u32::MAX; new_cols]; new_rows];
+
+        for i in 0..rows {
+            for j in 0..cols {
+                enhanced_map[i + 1][j + 1] = map[i][j];
+            }
+        }
+
+        let trailheads = enhanced_map
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(|(x, height)| if *height == 0 { Some((x, y)) } else { None })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(run_dfs(&enhanced_map, &trailheads))
+    }
+
+    fn part1((trailtails, _distinct_trails): &Self::Input) -> usize {
+        *trailtails
+    }
+
+    fn part2((_trailtails, distinct_trails): &Self::Input) -> usize {
+        *distinct_trails
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day10);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day10::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day10::part1(&data), 36);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day10::part2(&data), 81);
+    }
+}
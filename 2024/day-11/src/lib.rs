@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = Vec<u64>;
+type ProcessedInput = HashMap<u64, u64>;
+
+fn parse_input(input: &'static str) -> Result<ParsedInput, ParseError> {
+    input
+        .trim()
+        .split(' ')
+        .map(|number| parse_part(input, number))
+        .collect()
+}
+
+pub struct Day11;
+
+#[aoc_macros::solution(2024, 11)]
+impl Solution for Day11 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let stones = parse_input(input)?;
+        let mut stones_map = HashMap::new();
+
+        for stone in stones {
+            stones_map
+                .entry(stone)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+
+        Ok(stones_map)
+    }
+
+    fn part1(stones: &Self::Input) -> u64 {
+        stones_after_blinks(stones, 25)
+    }
+
+    fn part2(stones: &Self::Input) -> u64 {
+        stones_after_blinks(stones, 75)
+    }
+}
+
+fn stones_after_blinks(stones: &ProcessedInput, blinks: usize) -> u64 {
+    let mut stones = stones.clone();
+
+    for _ in 0..blinks {
+        let mut new_stones = HashMap::new();
+
+        for (stone, count) in stones {
+            if stone == 0 {
+                new_stones
+                    .entry(1)
+                    .and_modify(|new_count| *new_count += count)
+                    .or_insert(count);
+            } else {
+                let digits = stone.to_string();
+                if digits.len() % 2 == 0 {
+                    let mid = digits.len() / 2;
+                    new_stones
+                        .entry(digits[..mid].parse().unwrap())
+                        .and_modify(|new_count| *new_count += count)
+                        .or_insert(count);
+                    new_stones
+                        .entry(digits[mid..].parse().unwrap())
+                        .and_modify(|new_count| *new_count += count)
+                        .or_insert(count);
+                } else {
+                    new_stones
+                        .entry(stone * 2024)
+                        .and_modify(|new_count| *new_count += count)
+                        .or_insert(count);
+                }
+            }
+        }
+
+        stones = new_stones;
+    }
+
+    stones.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day11);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day11::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day11::part1(&data), 55312);
+    }
+
+    #[rstest]
+    #[case("125  17\n", 5)]
+    #[case("125 1x\n", 5)]
+    fn malformed_input(#[case] input: &'static str, #[case] column: usize) {
+        assert_eq!(Day11::try_parse(input).unwrap_err().column, column);
+    }
+}
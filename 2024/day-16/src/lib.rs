@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use aoc_utils::search::dijkstra;
+use aoc_utils::{Dir4, Grid, Solution};
+
+type ProcessedInput = (usize, usize);
+
+pub struct Day16;
+
+#[aoc_macros::solution(2024, 16)]
+impl Solution for Day16 {
+    type Input = ProcessedInput;
+
+    fn parse(input: &'static str) -> Self::Input {
+        let map: Grid<char> = input.parse().unwrap();
+        let start = map.position(&'S').unwrap();
+        let end = map.position(&'E').unwrap();
+
+        let search = dijkstra(
+            [(start, Dir4::Right)],
+            |&(position, dir)| {
+                let mut moves = vec![
+                    ((position, dir.turn_left()), 1000),
+                    ((position, dir.turn_right()), 1000),
+                ];
+                if let Some(next) = map.step(position, dir.offset().into()) {
+                    if map[next] != '#' {
+                        moves.push(((next, dir), 1));
+                    }
+                }
+                moves
+            },
+            |&(position, _)| position == end,
+        );
+
+        let tiles_on_shortest_paths: HashSet<(usize, usize)> = search
+            .on_any_path()
+            .into_iter()
+            .map(|(position, _)| position)
+            .collect();
+        (search.goal_cost().unwrap(), tiles_on_shortest_paths.len())
+    }
+
+    fn part1((min, _tiles_on_shortest_paths): &Self::Input) -> usize {
+        *min
+    }
+
+    fn part2((_min, tiles_on_shortest_paths): &Self::Input) -> usize {
+        *tiles_on_shortest_paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day16);
+
+    #[fixture]
+    fn data1() -> ProcessedInput {
+        let input = include_str!("test_input1.txt");
+        Day16::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> ProcessedInput {
+        let input = include_str!("test_input2.txt");
+        Day16::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test1(data1: ProcessedInput) {
+        assert_eq!(Day16::part1(&data1), 7036);
+    }
+
+    #[rstest]
+    fn part1_test2(data2: ProcessedInput) {
+        assert_eq!(Day16::part1(&data2), 11048);
+    }
+
+    #[rstest]
+    fn part2_test1(data1: ProcessedInput) {
+        assert_eq!(Day16::part2(&data1), 45);
+    }
+
+    #[rstest]
+    fn part2_test2(data2: ProcessedInput) {
+        assert_eq!(Day16::part2(&data2), 64);
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_utils::parse::parse_all;
+use aoc_utils::{ParseError, Solution};
+use winnow::ascii::{dec_uint, multispace0, multispace1};
+use winnow::combinator::{delimited, separated};
+use winnow::{PResult, Parser};
+
+type ParsedInput = (u64, u64, u64, Vec<u64>);
+type ProcessedInput = (u64, u64, u64, Vec<u64>);
+
+fn parse_input(input: &mut &str) -> PResult<ParsedInput> {
+    (
+        delimited("Register A: ", dec_uint, multispace1),
+        delimited("Register B: ", dec_uint, multispace1),
+        delimited("Register C: ", dec_uint, multispace1),
+        delimited(
+            "Program: ",
+            separated(1.., dec_uint::<_, u64, _>, ','),
+            multispace0,
+        ),
+    )
+        .parse_next(input)
+}
+
+pub struct Day17;
+
+#[aoc_macros::solution(2024, 17)]
+impl Solution for Day17 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(parse_input, input)
+    }
+
+    fn part1((a, b, c, program): &Self::Input) -> String {
+        run_program(*a, *b, *c, program)
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn part2((_a, b, c, program): &Self::Input) -> u64 {
+        dfs_a(*b, *c, program)
+    }
+}
+
+fn get_combo(operand: u64, a: u64, b: u64, c: u64) -> u64 {
+    match operand {
+        0..=3 => operand,
+        4 => a,
+        5 => b,
+        6 => c,
+        7 => panic!("Reserved operand {operand}"),
+        _ => panic!("Unsupported operand {operand}"),
+    }
+}
+
+fn run_program(a: u64, b: u64, c: u64, program: &[u64]) -> Vec<u64> {
+    let mut a = a;
+    let mut b = b;
+    let mut c = c;
+
+    let mut out: Vec<u64> = Vec::new();
+
+    let mut pp = 0;
+    while pp < program.len() {
+        let opcode = program[pp];
+        let operand = program[pp + 1];
+
+        match opcode {
+            0 => a /= 2u64.pow(get_combo(operand, a, b, c) as u32),
+            1 => b ^= operand,
+            2 => b = get_combo(operand, a, b, c) % 8,
+            3 => {
+                if a != 0 {
+                    pp = operand as usize;
+                    continue;
+                }
+            }
+            4 => b ^= c,
+            5 => out.push(get_combo(operand, a, b, c) % 8),
+            6 => b = a / 2u64.pow(get_combo(operand, a, b, c) as u32),
+            7 => c = a / 2u64.pow(get_combo(operand, a, b, c) as u32),
+            _ => panic!("Unsupported opcode {opcode}"),
+        }
+
+        pp += 2;
+    }
+
+    out
+}
+
+fn dfs_a(b: u64, c: u64, program: &[u64]) -> u64 {
+    let mut stack: Vec<(u64, usize)> = vec![(0, program.len() - 1)];
+    let mut results = Vec::new();
+
+    while let Some((a, pos)) = stack.pop() {
+        let target = program[pos];
+
+        for val in 0..8 {
+            let mut new_a = a;
+            let mask = val << (pos * 3);
+            new_a ^= mask;
+
+            let res = run_program(new_a, b, c, program);
+            if res.len() == program.len() && res[pos] == target {
+                if pos == 0 {
+                    results.push(new_a);
+                } else {
+                    stack.push((new_a, pos - 1));
+                }
+            }
+        }
+    }
+
+    results.sort_unstable();
+    results[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day17);
+
+    #[fixture]
+    fn data1() -> ProcessedInput {
+        let input = include_str!("test_input1.txt");
+        Day17::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> ProcessedInput {
+        let input = include_str!("test_input2.txt");
+        Day17::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data1: ProcessedInput) {
+        assert_eq!(Day17::part1(&data1), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[rstest]
+    fn part2_test(data2: ProcessedInput) {
+        assert_eq!(Day17::part2(&data2), 117_440);
+    }
+}
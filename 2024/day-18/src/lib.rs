@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+use winnow::combinator::separated_pair;
+use winnow::{ModalResult, Parser};
+
+use aoc_utils::parse::{lines, number, parse_all};
+use aoc_utils::search::bfs;
+use aoc_utils::{Grid, ParseError, Solution};
+
+type ParsedInput = Vec<(usize, usize)>;
+type ProcessedInput = Vec<(usize, usize)>;
+
+fn parse_input(input: &mut &str) -> ModalResult<ParsedInput> {
+    lines(separated_pair(number, ',', number)).parse_next(input)
+}
+
+pub struct Day18;
+
+#[aoc_macros::solution(2024, 18)]
+impl Solution for Day18 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(parse_input, input)
+    }
+
+    fn part1(bytes: &Self::Input) -> u64 {
+        part1_parameterized(bytes, 71, 1024)
+    }
+
+    fn part2(bytes: &Self::Input) -> String {
+        part2_parameterized(bytes, 71)
+    }
+}
+
+fn shortest_path(bytes: &ProcessedInput, size: usize, byte_count: usize) -> Option<u64> {
+    let mut map = Grid::filled(size, size, true);
+
+    for &byte in bytes.iter().take(byte_count) {
+        map[byte] = false;
+    }
+
+    bfs(
+        [(0, 0)],
+        |&position| map.neighbors4(position).filter(|&next| map[next]),
+        |&position| position == (size - 1, size - 1),
+    )
+    .goal_cost()
+    .map(|steps| steps as u64)
+}
+
+fn part1_parameterized(bytes: &ProcessedInput, size: usize, byte_count: usize) -> u64 {
+    shortest_path(bytes, size, byte_count).unwrap()
+}
+
+fn part2_parameterized(bytes: &ProcessedInput, size: usize) -> String {
+    let limit = (0..bytes.len()).collect::<Vec<_>>().binary_search_by(|i| {
+        if shortest_path(bytes, size, *i).is_some() {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    });
+
+    let pos = limit.unwrap_err();
+
+    format!("{},{}", bytes[pos - 1].0, bytes[pos - 1].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_macros::test_answers!(
+        Day18,
+        example(
+            "test_input.txt",
+            part1 = part1_parameterized(7, 12) => 22,
+            part2 = part2_parameterized(7) => "6,1",
+        ),
+    );
+}
@@ -0,0 +1,90 @@
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = (Vec<&'static str>, Vec<&'static str>);
+type ProcessedInput = Vec<usize>;
+
+fn parse_input(input: &'static str) -> Result<ParsedInput, ParseError> {
+    let parts = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            input,
+            input.len(),
+            "expected a blank line after the patterns",
+        )
+    })?;
+
+    Ok((
+        parts.0.split(", ").collect::<Vec<_>>(),
+        parts
+            .1
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>(),
+    ))
+}
+
+fn possible_arrangements(design: &str, patterns: &[&str]) -> usize {
+    let mut arrangements = vec![0; design.len()];
+
+    for index in (0..design.len()).rev() {
+        let sub_design = &design[index..];
+
+        for &pattern in patterns {
+            if sub_design == pattern {
+                arrangements[index] += 1;
+            } else if sub_design.starts_with(pattern) {
+                arrangements[index] += arrangements[index + pattern.len()];
+            }
+        }
+    }
+
+    arrangements[0]
+}
+
+pub struct Day19;
+
+#[aoc_macros::solution(2024, 19)]
+impl Solution for Day19 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let (patterns, designs) = parse_input(input)?;
+
+        Ok(designs
+            .iter()
+            .map(|design| possible_arrangements(design, &patterns))
+            .collect())
+    }
+
+    fn part1(arrangements: &Self::Input) -> usize {
+        arrangements.iter().filter(|&&a| a > 0).count()
+    }
+
+    fn part2(arrangements: &Self::Input) -> usize {
+        arrangements.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day19);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day19::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day19::part1(&data), 6);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day19::part2(&data), 16);
+    }
+}
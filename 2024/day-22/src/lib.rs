@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_utils::parse::parse_part;
+use aoc_utils::{ParseError, Solution};
+
+type ParsedInput = Vec<i64>;
+type ProcessedInput = Vec<Vec<(i64, i64, i64)>>;
+
+fn parse_input(input: &'static str) -> Result<ParsedInput, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_part(input, line))
+        .collect()
+}
+
+fn evolve_secret(secret: i64) -> Vec<(i64, i64, i64)> {
+    let mut result = vec![(0, 0, 0); 2000];
+    let mut new_secret = secret;
+    let mut last_price = new_secret % 10;
+
+    for res in &mut result {
+        new_secret = ((new_secret * 64) ^ new_secret) % 16_777_216;
+        new_secret = ((new_secret / 32) ^ new_secret) % 16_777_216;
+        new_secret = ((new_secret * 2048) ^ new_secret) % 16_777_216;
+
+        let new_price = new_secret % 10;
+        let change = new_price - last_price;
+        last_price = new_price;
+
+        *res = (new_secret, new_price, change);
+    }
+
+    result
+}
+
+pub struct Day22;
+
+#[aoc_macros::solution(2024, 22)]
+impl Solution for Day22 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let secrets = parse_input(input)?;
+
+        Ok(secrets.into_iter().map(evolve_secret).collect())
+    }
+
+    fn part1(secrets: &Self::Input) -> i64 {
+        secrets.iter().map(|secret| secret.last().unwrap().0).sum()
+    }
+
+    fn part2(secrets: &Self::Input) -> i64 {
+        let mut bananas: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
+
+        for secret in secrets {
+            let mut seen: HashSet<(i64, i64, i64, i64)> = HashSet::new();
+
+            for i in 3..secret.len() {
+                let history = (
+                    secret[i - 3].2,
+                    secret[i - 2].2,
+                    secret[i - 1].2,
+                    secret[i].2,
+                );
+
+                if seen.contains(&history) {
+                    continue;
+                }
+                seen.insert(history);
+
+                *bananas.entry(history).or_insert(0) += secret[i].1;
+            }
+        }
+
+        *bananas.values().max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day22);
+
+    #[fixture]
+    fn data1() -> ProcessedInput {
+        let input = include_str!("test_input1.txt");
+        Day22::parse(input)
+    }
+
+    #[fixture]
+    fn data2() -> ProcessedInput {
+        let input = include_str!("test_input2.txt");
+        Day22::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data1: ProcessedInput) {
+        assert_eq!(Day22::part1(&data1), 37_327_623);
+    }
+
+    #[rstest]
+    fn part2_test(data2: ProcessedInput) {
+        assert_eq!(Day22::part2(&data2), 23);
+    }
+}
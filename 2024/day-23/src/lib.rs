@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use aoc_utils::graph::Graph;
+use aoc_utils::{ParseError, Solution};
+
+type ProcessedInput = Graph<&'static str>;
+
+pub struct Day23;
+
+#[aoc_macros::solution(2024, 23)]
+impl Solution for Day23 {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        let mut graph = Graph::undirected();
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let (a, b) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, line, "expected `kh-tc`"))?;
+            graph.add_edge(a, b);
+        }
+
+        Ok(graph)
+    }
+
+    fn part1(graph: &Self::Input) -> usize {
+        let mut cliques_with_historian = HashSet::new();
+
+        for a in (0..graph.len()).filter(|&a| graph.label(a).starts_with('t')) {
+            for b in graph.neighbors(a) {
+                for c in graph.neighbors(b) {
+                    if graph.has_edge(c, a) {
+                        let mut abc = [a, b, c];
+                        abc.sort_unstable();
+                        cliques_with_historian.insert(abc);
+                    }
+                }
+            }
+        }
+
+        cliques_with_historian.len()
+    }
+
+    fn part2(graph: &Self::Input) -> String {
+        let mut clique_names: Vec<&str> = graph
+            .max_clique()
+            .into_iter()
+            .map(|id| *graph.label(id))
+            .collect();
+
+        clique_names.sort_unstable();
+        clique_names.join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day23);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day23::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day23::part1(&data), 7);
+    }
+
+    #[rstest]
+    fn part2_test(data: ProcessedInput) {
+        assert_eq!(Day23::part2(&data), "co,de,ka,ta");
+    }
+
+    #[rstest]
+    #[case("kh-tc\nqp kh\n", 2)]
+    #[case("kh-tc\nqp", 2)]
+    fn malformed_input(#[case] input: &'static str, #[case] line: usize) {
+        assert_eq!(Day23::try_parse(input).unwrap_err().line, line);
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_utils::Solution;
+use itertools::Itertools;
+
+type ParsedInput = Vec<Vec<Vec<char>>>;
+type ProcessedInput = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+fn parse_input(input: &'static str) -> ParsedInput {
+    input
+        .split("\n\n")
+        .filter(|s| !s.is_empty())
+        .map(|schematic| {
+            schematic
+                .lines()
+                .map(|line| line.chars().collect())
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day25;
+
+#[aoc_macros::solution(2024, 25)]
+impl Solution for Day25 {
+    type Input = ProcessedInput;
+
+    fn parse(input: &'static str) -> Self::Input {
+        let schematics = parse_input(input);
+
+        let mut locks = Vec::new();
+        let mut keys = Vec::new();
+
+        for schematic in schematics {
+            if schematic[0].iter().all(|&c| c == '#') {
+                let empty: Vec<usize> = (0..schematic[0].len())
+                    .map(|x| {
+                        (0..schematic.len())
+                            .filter(|&y| schematic[y][x] == '.')
+                            .count()
+                    })
+                    .collect();
+                locks.push(empty);
+            } else {
+                let full: Vec<usize> = (0..schematic[0].len())
+                    .map(|x| {
+                        (0..schematic.len())
+                            .filter(|&y| schematic[y][x] == '#')
+                            .count()
+                    })
+                    .collect();
+                keys.push(full);
+            }
+        }
+
+        (locks, keys)
+    }
+
+    fn part1((locks, keys): &Self::Input) -> usize {
+        locks
+            .iter()
+            .cartesian_product(keys)
+            .filter(|(lock, key)| {
+                lock.iter()
+                    .zip(key.iter())
+                    .all(|(lock_empty, key_full)| lock_empty >= key_full)
+            })
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    aoc_macros::test_answers!(Day25);
+
+    #[fixture]
+    fn data() -> ProcessedInput {
+        let input = include_str!("test_input.txt");
+        Day25::parse(input)
+    }
+
+    #[rstest]
+    fn part1_test(data: ProcessedInput) {
+        assert_eq!(Day25::part1(&data), 3);
+    }
+}
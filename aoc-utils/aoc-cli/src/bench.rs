@@ -0,0 +1,45 @@
+use aoc_utils::bench::BenchOptions;
+use aoc_utils::runner::RunArgs;
+use aoc_utils::{Part, Profile};
+use clap::Args;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use crate::parse_part;
+use crate::run::run_year;
+
+#[derive(Args)]
+pub struct BenchCommand {
+    year: u32,
+    #[arg(required_unless_present = "all")]
+    day: Option<u32>,
+    /// Benchmark every day of the year
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Only benchmark this part
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// Read the input from this file instead of the cache
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = BenchOptions::default().warmup)]
+    warmup: u32,
+    /// Timed runs to take the statistics over
+    #[arg(long, default_value_t = BenchOptions::default().samples)]
+    samples: u32,
+}
+
+/// Benchmarks are always built with optimizations and recorded in the cache's history.
+pub fn bench(profile: &Profile, command: BenchCommand) -> ExitCode {
+    let args = RunArgs {
+        day: command.day,
+        part: command.part,
+        input: command.input,
+        bench: Some(BenchOptions {
+            warmup: command.warmup,
+            samples: command.samples,
+        }),
+    };
+    run_year(profile, command.year, args, true)
+}
@@ -0,0 +1,188 @@
+use aoc_utils::page::Example;
+use aoc_utils::{AocError, Profile, PuzzlePage};
+use clap::Args;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::run::REPOSITORY;
+
+#[derive(Args)]
+pub struct ExamplesCommand {
+    year: u32,
+    day: u32,
+    /// Overwrite example inputs that already exist
+    #[arg(long)]
+    force: bool,
+    /// Only print the proposed examples
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// Proposes example inputs and answers from the puzzle text and writes them into the day crate,
+/// where they can be reviewed with `git diff`.
+pub fn examples(profile: &Profile, command: ExamplesCommand) -> Result<ExitCode, AocError> {
+    let (year, day) = (command.year, command.day);
+    let examples = fetch_examples(profile, year, day)?;
+    if examples.is_empty() {
+        eprintln!("Found no examples for {year} day {day}.");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let files = file_names(examples.len());
+    if command.dry_run {
+        for (file, example) in files.iter().zip(&examples) {
+            println!("{file}:\n{}", example.input);
+        }
+        println!("{}", example_table(&examples).join("\n"));
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let src = day_src(year, day);
+    if !src.exists() {
+        eprintln!("{} doesn't exist.", src.display());
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let mut success = write_examples(&src, &examples, command.force);
+
+    let lib = src.join("lib.rs");
+    let invocation = format!("aoc_macros::test_answers!(Day{day:02});");
+    match fs::read_to_string(&lib) {
+        Ok(contents) if contents.contains(&invocation) => {
+            let replacement = test_answers(day, &example_table(&examples));
+            match fs::write(&lib, contents.replacen(&invocation, &replacement, 1)) {
+                Ok(()) => println!("Added the expected answers to lib.rs."),
+                Err(err) => {
+                    eprintln!("Failed to write {}: {err}", lib.display());
+                    success = false;
+                }
+            }
+        }
+        _ => println!(
+            "Add the expected answers to test_answers! in lib.rs:\n{}",
+            example_table(&examples).join("\n")
+        ),
+    }
+
+    Ok(if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+pub(crate) fn fetch_examples(
+    profile: &Profile,
+    year: u32,
+    day: u32,
+) -> Result<Vec<Example>, AocError> {
+    let html = profile.fetcher().fetch_problem(year, day)?;
+    let page =
+        PuzzlePage::parse(&html).map_err(|reason| AocError::PageParse { year, day, reason })?;
+    Ok(page.examples())
+}
+
+pub(crate) fn day_src(year: u32, day: u32) -> PathBuf {
+    Path::new(REPOSITORY)
+        .join(year.to_string())
+        .join(format!("day-{day:02}"))
+        .join("src")
+}
+
+/// Writes the example inputs, keeping existing ones unless `force` is set. Returns whether all
+/// writes succeeded.
+pub(crate) fn write_examples(src: &Path, examples: &[Example], force: bool) -> bool {
+    let mut success = true;
+    for (file, example) in file_names(examples.len()).iter().zip(examples) {
+        let path = src.join(file);
+        if path.exists() && !force {
+            eprintln!("Kept {file}, which already exists. Pass --force to overwrite it.");
+            continue;
+        }
+        match fs::write(&path, &example.input) {
+            Ok(()) => println!("Wrote {file}."),
+            Err(err) => {
+                eprintln!("Failed to write {}: {err}", path.display());
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+pub(crate) fn example_table(examples: &[Example]) -> Vec<String> {
+    file_names(examples.len())
+        .iter()
+        .zip(examples)
+        .map(|(file, example)| example_args(file, example))
+        .collect()
+}
+
+/// The `test_answers!` invocation of a day with the given example rows, laid out like rustfmt
+/// would inside a `tests` module.
+pub(crate) fn test_answers(day: u32, table: &[String]) -> String {
+    let line = format!(
+        "aoc_macros::test_answers!(Day{day:02}, {});",
+        table.join(", ")
+    );
+    if line.len() + 4 <= 100 {
+        return line;
+    }
+    let rows: String = table
+        .iter()
+        .map(|row| format!("        {row},\n"))
+        .collect();
+    format!("aoc_macros::test_answers!(\n        Day{day:02},\n{rows}    );")
+}
+
+/// A single example goes into `test_input.txt`, several into `test_input1.txt`, ...
+fn file_names(count: usize) -> Vec<String> {
+    match count {
+        1 => vec!["test_input.txt".to_string()],
+        _ => (1..=count).map(|i| format!("test_input{i}.txt")).collect(),
+    }
+}
+
+/// The example's row in `test_answers!`, e.g. `example("test_input.txt", part1 = 11)`.
+fn example_args(file: &str, example: &Example) -> String {
+    let mut args = vec![format!("{file:?}")];
+    for (part, answer) in [("part1", &example.part1), ("part2", &example.part2)] {
+        if let Some(answer) = answer {
+            // Numbers compare equal to any integer type, everything else is a string.
+            let value = match answer.parse::<i64>() {
+                Ok(_) => answer.clone(),
+                Err(_) => format!("{answer:?}"),
+            };
+            args.push(format!("{part} = {value}"));
+        }
+    }
+    format!("example({})", args.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1, &["test_input.txt"])]
+    #[case(2, &["test_input1.txt", "test_input2.txt"])]
+    fn file_names_test(#[case] count: usize, #[case] expected: &[&str]) {
+        assert_eq!(file_names(count), expected);
+    }
+
+    #[rstest]
+    fn example_args_test() {
+        let example = Example {
+            input: String::new(),
+            part1: Some("11".to_string()),
+            part2: Some("6,1".to_string()),
+        };
+        assert_eq!(
+            example_args("test_input.txt", &example),
+            r#"example("test_input.txt", part1 = 11, part2 = "6,1")"#
+        );
+    }
+}
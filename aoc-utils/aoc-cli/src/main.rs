@@ -0,0 +1,141 @@
+use aoc_utils::profile::selected_profile;
+use aoc_utils::submit::submit_answer_with;
+use aoc_utils::{AocError, Part, Profile, SubmitOutcome};
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+mod bench;
+mod examples;
+mod new;
+mod run;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code helper")]
+struct Cli {
+    /// Profile whose session and cache to use [default: $AOC_PROFILE or "default"]
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Submit an answer for one part of a puzzle
+    Submit {
+        year: u32,
+        day: u32,
+        #[arg(value_parser = parse_part)]
+        part: Part,
+        answer: String,
+    },
+    /// Inspect or modify the local cache
+    #[command(subcommand)]
+    Cache(CacheCommand),
+    /// List the configured profiles
+    Profiles,
+    /// Run solutions and print a table of their answers and timings
+    Run(run::RunCommand),
+    /// Create a day crate with its input and examples
+    New(new::NewCommand),
+    /// Extract example inputs and answers from the puzzle text into the day crate
+    Examples(examples::ExamplesCommand),
+    /// Benchmark solutions and flag regressions against earlier runs on the same input
+    Bench(bench::BenchCommand),
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Print the cache directory
+    Path,
+    /// Check cached inputs against the manifest and for bad downloads
+    Verify,
+    /// Forget the cached answers of a day
+    Invalidate { year: u32, day: u32 },
+}
+
+pub(crate) fn parse_part(part: &str) -> Result<Part, String> {
+    let part: u8 = part
+        .parse()
+        .map_err(|_| format!("{part} isn't a part number."))?;
+    Part::try_from(part)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let profile = cli.profile.unwrap_or_else(selected_profile);
+
+    let result = match cli.command {
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => Profile::get(&profile).and_then(|profile| submit(profile, year, day, part, &answer)),
+        Command::Cache(command) => {
+            Profile::get(&profile).and_then(|profile| cache(profile, command))
+        }
+        Command::New(command) => {
+            Profile::get(&profile).and_then(|profile| new::new(profile, command))
+        }
+        Command::Examples(command) => {
+            Profile::get(&profile).and_then(|profile| examples::examples(profile, command))
+        }
+        Command::Profiles => profiles(),
+        Command::Run(command) => Profile::get(&profile).map(|profile| run::run(profile, command)),
+        Command::Bench(command) => {
+            Profile::get(&profile).map(|profile| bench::bench(profile, command))
+        }
+    };
+
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err.report());
+        ExitCode::FAILURE
+    })
+}
+
+fn submit(
+    profile: &Profile,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<ExitCode, AocError> {
+    let outcome = submit_answer_with(profile.fetcher(), profile.cache(), year, day, part, answer)?;
+    println!("{outcome}");
+    Ok(match outcome {
+        SubmitOutcome::Correct | SubmitOutcome::AlreadySolved => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    })
+}
+
+fn cache(profile: &Profile, command: CacheCommand) -> Result<ExitCode, AocError> {
+    let cache = profile.cache();
+
+    match command {
+        CacheCommand::Path => println!("{}", cache.dir().display()),
+        CacheCommand::Verify => {
+            let issues = cache.verify()?;
+            for issue in &issues {
+                println!("{issue}");
+            }
+            if !issues.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+            println!("All cached inputs are valid.");
+        }
+        CacheCommand::Invalidate { year, day } => cache.invalidate(year, day)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn profiles() -> Result<ExitCode, AocError> {
+    for name in Profile::names()? {
+        match Profile::get(&name)?.fetcher().session() {
+            Ok(session) => println!("{name}: {session}"),
+            Err(_) => println!("{name}: no session"),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
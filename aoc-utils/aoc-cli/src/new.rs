@@ -0,0 +1,246 @@
+use aoc_utils::page::Example;
+use aoc_utils::{is_offline, AocError, Profile};
+use clap::Args;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+use crate::examples::{day_src, example_table, fetch_examples, test_answers, write_examples};
+use crate::run::REPOSITORY;
+
+const YEAR_CARGO_TOML: &str = include_str!("../templates/year-Cargo.toml");
+const RUNNER_CARGO_TOML: &str = include_str!("../templates/runner-Cargo.toml");
+const RUNNER_MAIN: &str = include_str!("../templates/runner-main.rs");
+const DAY_CARGO_TOML: &str = include_str!("../templates/day-Cargo.toml");
+const DAY_LIB: &str = include_str!("../templates/day-lib.rs");
+const DAY_MAIN: &str = include_str!("../templates/day-main.rs");
+
+const PART2: &str = "
+    fn part2(_lines: &Self::Input) -> usize {
+        0
+    }
+";
+
+#[derive(Args)]
+pub struct NewCommand {
+    year: u32,
+    day: u32,
+}
+
+/// Creates a day crate, and its year's workspace if needed, registers it with the year's runner
+/// and fills in the input and examples.
+pub fn new(profile: &Profile, command: NewCommand) -> Result<ExitCode, AocError> {
+    let (year, day) = (command.year, command.day);
+    if !(1..=25).contains(&day) {
+        eprintln!("{day} isn't a day of Advent of Code.");
+        return Ok(ExitCode::FAILURE);
+    }
+    let src = day_src(year, day);
+    let Some(day_dir) = src.parent().filter(|dir| !dir.exists()) else {
+        eprintln!("day-{day:02} already exists.");
+        return Ok(ExitCode::FAILURE);
+    };
+
+    let year_dir = Path::new(REPOSITORY).join(year.to_string());
+    let scaffold = || -> io::Result<()> {
+        if !year_dir.join("Cargo.toml").exists() {
+            create_year(&year_dir, year)?;
+            println!("Created the {year} workspace.");
+        }
+        create_day(day_dir, year, day)?;
+        register(&year_dir.join("runner"), day)
+    };
+    if let Err(err) = scaffold() {
+        eprintln!("Failed to create day-{day:02}: {err}");
+        return Ok(ExitCode::FAILURE);
+    }
+    println!("Created day-{day:02}.");
+
+    if is_offline() {
+        println!("Skipped downloading the input and examples, since AOC_OFFLINE is set.");
+    } else {
+        match profile.cache().input_file(profile.fetcher(), year, day) {
+            Ok(path) => println!("Downloaded the input to {}.", path.display()),
+            Err(err) => eprintln!("{}", err.report()),
+        }
+    }
+
+    let mut examples = if is_offline() {
+        Vec::new()
+    } else {
+        fetch_examples(profile, year, day).unwrap_or_else(|err| {
+            eprintln!("{}", err.report());
+            Vec::new()
+        })
+    };
+    // Without an example, an empty one keeps the skeleton compiling until it's filled in.
+    if examples.is_empty() {
+        eprintln!("Fill in test_input.txt and the expected answers of the example.");
+        examples.push(Example {
+            input: String::new(),
+            part1: Some("?".to_string()),
+            part2: None,
+        });
+    }
+    let lib = day_lib(year, day, &test_answers(day, &example_table(&examples)));
+    if let Err(err) = fs::write(src.join("lib.rs"), lib) {
+        eprintln!("Failed to write lib.rs: {err}");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(if write_examples(&src, &examples, false) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn fill(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{DD}}", &format!("{day:02}"))
+}
+
+fn day_lib(year: u32, day: u32, test_answers: &str) -> String {
+    // The last day has no second part.
+    let part2 = if day == 25 { "" } else { PART2 };
+    fill(DAY_LIB, year, day)
+        .replace("{{PART2}}", part2)
+        .replace("{{TEST_ANSWERS}}", test_answers)
+}
+
+fn create_year(year_dir: &Path, year: u32) -> io::Result<()> {
+    let runner = year_dir.join("runner");
+    fs::create_dir_all(runner.join("src"))?;
+    fs::write(year_dir.join("Cargo.toml"), YEAR_CARGO_TOML)?;
+    fs::write(runner.join("Cargo.toml"), fill(RUNNER_CARGO_TOML, year, 0))?;
+    fs::write(runner.join("src").join("main.rs"), RUNNER_MAIN)
+}
+
+fn create_day(day_dir: &Path, year: u32, day: u32) -> io::Result<()> {
+    fs::create_dir_all(day_dir.join("src"))?;
+    fs::write(day_dir.join("Cargo.toml"), fill(DAY_CARGO_TOML, year, day))?;
+    fs::write(
+        day_dir.join("src").join("main.rs"),
+        fill(DAY_MAIN, year, day),
+    )
+}
+
+const DAYS_START: &str = "const DAYS: &[Day] = &[";
+
+/// Adds the day to the runner's dependencies and `DAYS`.
+fn register(runner: &Path, day: u32) -> io::Result<()> {
+    let cargo_toml = runner.join("Cargo.toml");
+    let dependency = format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}");
+    let contents = fs::read_to_string(&cargo_toml)?;
+    fs::write(&cargo_toml, insert_sorted(&contents, "day-", &dependency))?;
+
+    let main = runner.join("src").join("main.rs");
+    let contents = fs::read_to_string(&main)?;
+    let contents = add_day(&contents, day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has no `{DAYS_START}` list", main.display()),
+        )
+    })?;
+    fs::write(&main, contents)
+}
+
+/// Inserts `line` after the last line starting with `prefix` that sorts before it, or at the end.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let position = match lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix) && *l < line)
+    {
+        Some(before) => before + 1,
+        None => lines
+            .iter()
+            .position(|l| l.starts_with(prefix))
+            .unwrap_or(lines.len()),
+    };
+    lines.insert(position, line);
+    lines.join("\n") + "\n"
+}
+
+/// Rewrites the `DAYS` list of a runner with `day` added, laid out like rustfmt would.
+fn add_day(contents: &str, day: u32) -> Option<String> {
+    let start = contents.find(DAYS_START)?;
+    let end = start + contents[start..].find("];")? + 2;
+
+    let mut days: Vec<u32> = contents[start + DAYS_START.len()..end]
+        .split("day_")
+        .skip(1)
+        .filter_map(|entry| entry.get(..2)?.parse().ok())
+        .collect();
+    days.push(day);
+    days.sort_unstable();
+    days.dedup();
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|day| format!("Day::of::<day_{day:02}::Day{day:02}>()"))
+        .collect();
+    let line = format!("{DAYS_START}{}];", entries.join(", "));
+    let list = if line.len() <= 100 {
+        line
+    } else {
+        let rows: String = entries
+            .iter()
+            .map(|entry| format!("    {entry},\n"))
+            .collect();
+        format!("{DAYS_START}\n{rows}];")
+    };
+    Some(format!("{}{list}{}", &contents[..start], &contents[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        "[dependencies]\naoc-utils = { workspace = true }\n",
+        "day-03 = 3",
+        "[dependencies]\naoc-utils = { workspace = true }\nday-03 = 3\n"
+    )]
+    #[case(
+        "deps\nday-01 = 1\nday-05 = 5\n",
+        "day-03 = 3",
+        "deps\nday-01 = 1\nday-03 = 3\nday-05 = 5\n"
+    )]
+    #[case("deps\nday-04 = 4\n", "day-03 = 3", "deps\nday-03 = 3\nday-04 = 4\n")]
+    fn insert_dependency(#[case] contents: &str, #[case] line: &str, #[case] expected: &str) {
+        assert_eq!(insert_sorted(contents, "day-", line), expected);
+    }
+
+    #[rstest]
+    fn add_days() {
+        let main = add_day(RUNNER_MAIN, 3).unwrap();
+        assert!(main.contains("const DAYS: &[Day] = &[Day::of::<day_03::Day03>()];\n"));
+
+        let main = add_day(&main, 1).unwrap();
+        assert!(main.contains(
+            "const DAYS: &[Day] = &[Day::of::<day_01::Day01>(), Day::of::<day_03::Day03>()];\n"
+        ));
+
+        let main = add_day(&main, 2).unwrap();
+        assert!(main.contains(
+            "const DAYS: &[Day] = &[\n    Day::of::<day_01::Day01>(),\n    Day::of::<day_02::Day02>(),\n    Day::of::<day_03::Day03>(),\n];\n"
+        ));
+        assert!(main.ends_with("aoc_utils::runner::main(DAYS)\n}\n"));
+    }
+
+    #[rstest]
+    #[case(3, true)]
+    #[case(25, false)]
+    fn day_lib_parts(#[case] day: u32, #[case] has_part2: bool) {
+        let lib = day_lib(2025, day, "aoc_macros::test_answers!(Day03);");
+        assert_eq!(lib.contains("fn part2"), has_part2);
+        assert!(!lib.contains("{{"));
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_utils::runner::RunArgs;
+use aoc_utils::{Part, Profile};
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+
+use crate::parse_part;
+
+/// The repository root, which contains one workspace per year.
+pub(crate) const REPOSITORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+#[derive(Args)]
+pub struct RunCommand {
+    year: u32,
+    #[arg(required_unless_present = "all")]
+    day: Option<u32>,
+    /// Run every day of the year
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Only run this part
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+    /// Read the input from this file instead of the cache
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Build the solutions without optimizations
+    #[arg(long)]
+    debug: bool,
+}
+
+pub fn run(profile: &Profile, command: RunCommand) -> ExitCode {
+    let args = RunArgs {
+        day: command.day,
+        part: command.part,
+        input: command.input,
+        bench: None,
+    };
+    run_year(profile, command.year, args, !command.debug)
+}
+
+/// Each year's days are registered in that year's `runner` crate, which is run through cargo.
+pub fn run_year(profile: &Profile, year: u32, mut args: RunArgs, release: bool) -> ExitCode {
+    let manifest = Path::new(REPOSITORY)
+        .join(year.to_string())
+        .join("Cargo.toml");
+    if !manifest.exists() {
+        eprintln!("There are no solutions for {year}.");
+        return ExitCode::FAILURE;
+    }
+
+    // The runner is started in another directory.
+    args.input = args
+        .input
+        .map(|input| input.canonicalize().unwrap_or(input));
+
+    match cargo_command(&manifest, profile, year, &args, release).status() {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("Failed to run cargo: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn cargo_command(
+    manifest: &Path,
+    profile: &Profile,
+    year: u32,
+    args: &RunArgs,
+    release: bool,
+) -> process::Command {
+    let mut cargo = process::Command::new(env!("CARGO"));
+    cargo
+        .args(["run", "--quiet", "--manifest-path"])
+        .arg(manifest)
+        .args(["-p", &format!("aoc-{year}")])
+        // The runner, the inputs baked into the days and the bench history all follow the profile.
+        .env("AOC_PROFILE", profile.name());
+    if release {
+        cargo.arg("--release");
+    }
+    cargo.arg("--").args(args.to_args());
+    cargo
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::config::Config;
+    use rstest::rstest;
+    use std::ffi::OsStr;
+
+    use super::*;
+
+    #[rstest]
+    fn cargo_command_passes_profile() {
+        let profile = Profile::from_config("default", &Config::default()).unwrap();
+        let args = RunArgs {
+            day: Some(7),
+            part: None,
+            input: None,
+            bench: None,
+        };
+        let cargo = cargo_command(Path::new("2024/Cargo.toml"), &profile, 2024, &args, true);
+
+        let envs: Vec<_> = cargo.get_envs().collect();
+        assert_eq!(
+            envs,
+            [(OsStr::new("AOC_PROFILE"), Some(OsStr::new("default")))]
+        );
+        assert!(cargo.get_args().any(|arg| arg == "aoc-2024"));
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_utils::parse::{lines, parse_all};
+use aoc_utils::{ParseError, Solution};
+use winnow::ascii::till_line_ending;
+use winnow::{ModalResult, Parser};
+
+type ParsedInput = Vec<&'static str>;
+type ProcessedInput = Vec<&'static str>;
+
+fn parse_input(input: &mut &'static str) -> ModalResult<ParsedInput> {
+    lines(till_line_ending).parse_next(input)
+}
+
+pub struct Day{{DD}};
+
+#[aoc_macros::solution({{YEAR}}, {{DAY}})]
+impl Solution for Day{{DD}} {
+    type Input = ProcessedInput;
+
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError> {
+        parse_all(parse_input, input)
+    }
+
+    fn part1(_lines: &Self::Input) -> usize {
+        0
+    }
+{{PART2}}}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    {{TEST_ANSWERS}}
+}
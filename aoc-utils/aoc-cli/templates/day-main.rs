@@ -0,0 +1,6 @@
+use aoc_utils::runner::Day;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    Day::of::<day_{{DD}}::Day{{DD}}>().main()
+}
@@ -0,0 +1,8 @@
+use aoc_utils::runner::Day;
+use std::process::ExitCode;
+
+const DAYS: &[Day] = &[];
+
+fn main() -> ExitCode {
+    aoc_utils::runner::main(DAYS)
+}
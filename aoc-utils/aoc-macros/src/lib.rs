@@ -1,12 +1,25 @@
-use aoc_utils::get_input_file;
+use aoc_utils::profile::selected_profile;
+use aoc_utils::{get_input_file, get_input_file_for, get_offline_input_file_for, is_offline};
+use proc_macro2::Span;
 use quote::quote;
+use std::env;
+use std::path::PathBuf;
 use syn::parse::{Parse, ParseStream};
 use syn::token::Comma;
-use syn::{parse_macro_input, LitBool, LitInt};
+use syn::{parse_macro_input, Ident, ImplItem, ItemImpl, LitInt, LitStr, Token, Type};
+
+fn parse_profile(input: ParseStream) -> syn::Result<Option<LitStr>> {
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let _comma: Comma = input.parse()?;
+    Ok(Some(input.parse()?))
+}
 
 struct YearDayLit {
     year_lit: LitInt,
     day_lit: LitInt,
+    profile_lit: Option<LitStr>,
 }
 
 impl Parse for YearDayLit {
@@ -14,9 +27,11 @@ impl Parse for YearDayLit {
         let year = input.parse()?;
         let _comma: Comma = input.parse()?;
         let day = input.parse()?;
+        let profile = parse_profile(input)?;
         Ok(YearDayLit {
             year_lit: year,
             day_lit: day,
+            profile_lit: profile,
         })
     }
 }
@@ -43,9 +58,26 @@ impl TryInto<(u32, u32)> for YearDayLit {
     }
 }
 
+fn input_file(year: u32, day: u32, profile: Option<&str>) -> Result<String, String> {
+    let file_path = match (profile, is_offline()) {
+        (profile, true) => {
+            let profile = profile.map_or_else(selected_profile, str::to_string);
+            // Proc macros see the manifest directory of the crate being compiled.
+            let src_dir = env::var_os("CARGO_MANIFEST_DIR")
+                .map(|dir| PathBuf::from(dir).join("src"))
+                .unwrap_or_default();
+            get_offline_input_file_for(&profile, year, day, &src_dir)
+        }
+        (Some(profile), false) => get_input_file_for(profile, year, day),
+        (None, false) => get_input_file(year, day),
+    };
+    file_path.map_err(|e| e.report())
+}
+
 #[proc_macro]
 pub fn include_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let year_day_lit = parse_macro_input!(input as YearDayLit);
+    let profile = year_day_lit.profile_lit.as_ref().map(LitStr::value);
 
     let (year, day) = match year_day_lit.try_into() {
         Ok(year_day) => year_day,
@@ -57,10 +89,9 @@ pub fn include_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     };
 
-    let file_path = match get_input_file(year, day) {
+    let file_path = match input_file(year, day, profile.as_deref()) {
         Ok(path) => path,
-        Err(e) => {
-            let msg = e.to_string();
+        Err(msg) => {
             return quote! {
                 compile_error!(#msg)
             }
@@ -68,43 +99,301 @@ pub fn include_input(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         }
     };
 
+    // Reading the variables through option_env! makes cargo rebuild when they change.
+    let profile_tracking = profile.is_none().then(|| {
+        quote! {
+            const _: Option<&str> = option_env!("AOC_PROFILE");
+        }
+    });
+
     quote! {
-        (#year, #day, include_str!(#file_path))
+        {
+            #profile_tracking
+            const _: Option<&str> = option_env!("AOC_OFFLINE");
+            (#year, #day, include_str!(#file_path))
+        }
     }
     .into()
 }
 
-#[proc_macro]
-pub fn test_answers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let has_part2 = parse_macro_input!(input as LitBool).value;
+/// Completes an `impl Solution for ...` block with `YEAR`, `DAY`, `INPUT` and, if it
+/// doesn't define `part2`, `HAS_PART2`. It has to define `parse` or `try_parse`, and a `parse`
+/// alone also gets a `try_parse` that calls it.
+#[proc_macro_attribute]
+pub fn solution(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let year_day_lit = parse_macro_input!(args as YearDayLit);
+    let mut item = parse_macro_input!(item as ItemImpl);
+    let profile = year_day_lit.profile_lit.as_ref().map(LitStr::value);
 
-    let part2_test = if has_part2 {
-        quote! {
-            assert!(answers.1.is_some(), "The answer for part 2 hasn't been submitted yet.");
-            if let Some(answer) = answers.1 {
-                assert_eq!(answer, part2(&data).to_string(), "Part 2 is incorrect.");
+    let (year, day) = match year_day_lit.try_into() {
+        Ok(year_day) => year_day,
+        Err(msg) => {
+            return quote! {
+                compile_error!(#msg);
             }
+            .into();
         }
-    } else {
+    };
+
+    let input = match input_file(year, day, profile.as_deref()) {
+        Ok(path) => quote! { include_str!(#path) },
+        Err(msg) => quote! { compile_error!(#msg) },
+    };
+    let profile_tracking = profile.is_none().then(|| {
         quote! {
-            assert!(answers.1.is_none(), "An answer has been submitted for part 2.");
+            const _: Option<&str> = option_env!("AOC_PROFILE");
         }
+    });
+
+    let defines = |name: &str| {
+        item.items
+            .iter()
+            .any(|item| matches!(item, ImplItem::Fn(f) if f.sig.ident == name))
     };
+    let has_part2 = defines("part2");
+    if !defines("try_parse") {
+        if !defines("parse") {
+            return syn::Error::new_spanned(&item.self_ty, "implement `parse` or `try_parse`")
+                .to_compile_error()
+                .into();
+        }
+        item.items.push(syn::parse_quote! {
+            fn try_parse(
+                input: &'static str,
+            ) -> ::std::result::Result<Self::Input, aoc_utils::ParseError> {
+                ::std::result::Result::Ok(Self::parse(input))
+            }
+        });
+    }
+
+    let mut consts = vec![
+        quote! { const YEAR: u32 = #year; },
+        quote! { const DAY: u32 = #day; },
+        quote! {
+            const INPUT: &'static str = {
+                #profile_tracking
+                const _: Option<&str> = option_env!("AOC_OFFLINE");
+                #input
+            };
+        },
+    ];
+    if !has_part2 {
+        consts.push(quote! { const HAS_PART2: bool = false; });
+    }
+    for (i, tokens) in consts.into_iter().enumerate() {
+        item.items.insert(i, syn::parse_quote! { #tokens });
+    }
 
+    // Solutions return their concrete answer types, which the tests rely on.
     quote! {
-        #[rstest]
-        fn test_answers() {
-            let answers = aoc_utils::get_answers(INPUT.0, INPUT.1);
-            assert!(answers.is_ok(), "{}", answers.unwrap_err());
-            let answers = answers.unwrap();
-
-            let data = process_input(INPUT.2);
-            assert!(answers.0.is_some(), "The answer for part 1 hasn't been submitted yet.");
-            if let Some(answer) = answers.0 {
-                assert_eq!(answer, part1(&data).to_string(), "Part 1 is incorrect.");
+        #[allow(refining_impl_trait)]
+        #item
+    }
+    .into()
+}
+
+/// How a part is computed from the parsed input: the trait method, another function or a call
+/// whose first argument is left out.
+enum PartFn {
+    Default,
+    Path(syn::Path),
+    Call(syn::ExprCall),
+}
+
+impl PartFn {
+    fn parse_expr(expr: syn::Expr) -> syn::Result<Self> {
+        match expr {
+            syn::Expr::Path(path) => Ok(PartFn::Path(path.path)),
+            syn::Expr::Call(call) => Ok(PartFn::Call(call)),
+            expr => Err(syn::Error::new_spanned(
+                expr,
+                "expected a function or a call with the input left out",
+            )),
+        }
+    }
+
+    fn call(&self, solution: &Type, part: &Ident, data: &Ident) -> proc_macro2::TokenStream {
+        match self {
+            PartFn::Default => quote! { <#solution as aoc_utils::Solution>::#part(&#data) },
+            PartFn::Path(path) => quote! { #path(&#data) },
+            PartFn::Call(call) => {
+                let func = &call.func;
+                let args = call.args.iter();
+                quote! { #func(&#data, #(#args),*) }
             }
-            #part2_test
         }
     }
-    .into()
+}
+
+/// `example("test_input.txt", part1 = 12, part2 = part2_parameterized(7) => "6,1")`
+struct Example {
+    file: LitStr,
+    parts: Vec<(Ident, PartFn, syn::Expr)>,
+}
+
+impl Parse for Example {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let file = input.parse()?;
+        let mut parts = Vec::new();
+        while !input.is_empty() {
+            let _comma: Comma = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            let part = parse_part_name(input)?;
+            let _eq: Token![=] = input.parse()?;
+            let expr: syn::Expr = input.parse()?;
+            if input.peek(Token![=>]) {
+                let _arrow: Token![=>] = input.parse()?;
+                parts.push((part, PartFn::parse_expr(expr)?, input.parse()?));
+            } else {
+                parts.push((part, PartFn::Default, expr));
+            }
+        }
+        Ok(Example { file, parts })
+    }
+}
+
+fn parse_part_name(input: ParseStream) -> syn::Result<Ident> {
+    let name: Ident = input.parse()?;
+    if name != "part1" && name != "part2" {
+        return Err(syn::Error::new(name.span(), "expected `part1` or `part2`"));
+    }
+    Ok(name)
+}
+
+struct TestAnswersArgs {
+    solution: Type,
+    profile_lit: Option<LitStr>,
+    budget_ms: Option<LitInt>,
+    parse: Option<syn::Path>,
+    part1: PartFn,
+    part2: PartFn,
+    examples: Vec<Example>,
+}
+
+impl Parse for TestAnswersArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let solution = input.parse()?;
+        let mut args = TestAnswersArgs {
+            solution,
+            profile_lit: None,
+            budget_ms: None,
+            parse: None,
+            part1: PartFn::Default,
+            part2: PartFn::Default,
+            examples: Vec::new(),
+        };
+        while !input.is_empty() {
+            let _comma: Comma = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            if input.peek(LitStr) && args.profile_lit.is_none() {
+                args.profile_lit = Some(input.parse()?);
+                continue;
+            }
+            let name: Ident = input.parse()?;
+            if name == "example" {
+                let content;
+                syn::parenthesized!(content in input);
+                args.examples.push(content.parse()?);
+                continue;
+            }
+            let _eq: Token![=] = input.parse()?;
+            match name.to_string().as_str() {
+                "budget_ms" => args.budget_ms = Some(input.parse()?),
+                "parse" => args.parse = Some(input.parse()?),
+                "part1" => args.part1 = PartFn::parse_expr(input.parse()?)?,
+                "part2" => args.part2 = PartFn::parse_expr(input.parse()?)?,
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "expected `budget_ms`, `parse`, `part1`, `part2` or `example(...)`",
+                    ))
+                }
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// `test_answers!(Type[, "profile"][, budget_ms = <ms>][, parse = <fn>][, part1 = <fn>]
+/// [, part2 = <fn>][, example(...)]*)`
+///
+/// Generates `test_answer_part1` and `test_answer_part2`, which compare against the accepted
+/// answers if the `test-answers` feature is enabled. The budget of each part is only enforced in
+/// release builds. Every `example` generates a test per part, named after the input file.
+#[proc_macro]
+pub fn test_answers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(input as TestAnswersArgs);
+    let solution = &args.solution;
+    let budget = match &args.budget_ms {
+        Some(budget_ms) => quote! {
+            (!cfg!(debug_assertions)).then(|| std::time::Duration::from_millis(#budget_ms))
+        },
+        None => quote! { None },
+    };
+
+    let year = quote! { <#solution as aoc_utils::Solution>::YEAR };
+    let day = quote! { <#solution as aoc_utils::Solution>::DAY };
+    let get_answers = match &args.profile_lit {
+        Some(profile) => quote! { aoc_utils::get_answers_for(#profile, #year, #day) },
+        None => quote! { aoc_utils::get_answers(#year, #day) },
+    };
+    let parse = match &args.parse {
+        Some(path) => quote! { #path },
+        None => quote! { <#solution as aoc_utils::Solution>::parse },
+    };
+
+    let data = Ident::new("data", Span::call_site());
+    let mut tests = Vec::new();
+    for (part, part_fn, variant) in [
+        ("part1", &args.part1, quote! { One }),
+        ("part2", &args.part2, quote! { Two }),
+    ] {
+        let name = Ident::new(&format!("test_answer_{part}"), Span::call_site());
+        let call = part_fn.call(solution, &Ident::new(part, Span::call_site()), &data);
+        tests.push(quote! {
+            #[cfg(feature = "test-answers")]
+            #[rstest]
+            fn #name() {
+                let answers = #get_answers
+                    .unwrap_or_else(|err| panic!("{}", err.report()));
+                let #data = #parse(<#solution as aoc_utils::Solution>::INPUT);
+                aoc_utils::solution::check_answer::<#solution>(
+                    aoc_utils::Part::#variant,
+                    answers,
+                    || #call.to_string(),
+                    #budget,
+                );
+            }
+        });
+    }
+
+    for example in &args.examples {
+        let file = &example.file;
+        let stem: String = file
+            .value()
+            .trim_end_matches(".txt")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        for (part, part_fn, expected) in &example.parts {
+            let name = Ident::new(&format!("{stem}_{part}"), file.span());
+            let call = part_fn.call(solution, part, &data);
+            tests.push(quote! {
+                #[rstest]
+                fn #name() {
+                    let #data = #parse(include_str!(#file));
+                    assert_eq!(#call.to_string(), (#expected).to_string());
+                }
+            });
+        }
+    }
+
+    quote! { #(#tests)* }.into()
 }
@@ -0,0 +1,347 @@
+use crate::cache::{sha256, unix_time, update_json};
+use crate::runner::{format_duration, render_table, Day};
+use crate::{AocError, ParseError, Part};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const HISTORY_FILE: &str = "bench-history.json";
+/// How much slower the median has to get to count as a regression.
+pub const REGRESSION_THRESHOLD: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub warmup: u32,
+    pub samples: u32,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            samples: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as u64)
+            .collect();
+        nanos.sort_unstable();
+        let Some(&min_ns) = nanos.first() else {
+            return Stats {
+                min_ns: 0,
+                median_ns: 0,
+                mean_ns: 0,
+                stddev_ns: 0,
+            };
+        };
+
+        let n = nanos.len();
+        let median_ns = if n.is_multiple_of(2) {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2
+        } else {
+            nanos[n / 2]
+        };
+        let mean = nanos.iter().map(|&ns| ns as f64).sum::<f64>() / n as f64;
+        let variance = nanos
+            .iter()
+            .map(|&ns| (ns as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min_ns,
+            median_ns,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn stddev(&self) -> Duration {
+        Duration::from_nanos(self.stddev_ns)
+    }
+}
+
+/// The timings of one day, keyed by a hash of its input rather than a commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub input_hash: String,
+    pub timestamp: u64,
+    pub samples: u32,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Measurement {
+    pub fn stages(&self) -> Vec<(&'static str, Stats)> {
+        let mut stages = vec![("parse", self.parse)];
+        stages.extend(self.part1.map(|stats| ("part 1", stats)));
+        stages.extend(self.part2.map(|stats| ("part 2", stats)));
+        stages
+    }
+}
+
+/// Times parsing and the selected parts separately, after some untimed warmup runs.
+pub fn bench(
+    day: &Day,
+    input: &'static str,
+    part: Option<Part>,
+    options: BenchOptions,
+) -> Result<Measurement, ParseError> {
+    for _ in 0..options.warmup {
+        day.run(input, part)?;
+    }
+
+    let samples = options.samples.max(1);
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+    for _ in 0..samples {
+        let run = day.run(input, part)?;
+        parse.push(run.parse);
+        for answer in run.answers {
+            match answer.part {
+                Part::One => part1.push(answer.elapsed),
+                Part::Two => part2.push(answer.elapsed),
+            }
+        }
+    }
+
+    let stats = |samples: Vec<Duration>| (!samples.is_empty()).then(|| Stats::new(&samples));
+    Ok(Measurement {
+        year: day.year,
+        day: day.day,
+        input_hash: sha256(input.as_bytes()),
+        timestamp: unix_time(),
+        samples,
+        parse: Stats::new(&parse),
+        part1: stats(part1),
+        part2: stats(part2),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub stage: &'static str,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Regression {
+    pub fn slowdown(&self) -> f64 {
+        self.after.as_secs_f64() / self.before.as_secs_f64() - 1.0
+    }
+}
+
+/// Stages whose median got slower by more than `threshold` and by more than the noise.
+pub fn regressions(
+    previous: &Measurement,
+    current: &Measurement,
+    threshold: f64,
+) -> Vec<Regression> {
+    let before = previous.stages();
+    current
+        .stages()
+        .into_iter()
+        .filter_map(|(stage, after)| {
+            let (_, before) = before.iter().find(|(name, _)| *name == stage)?;
+            let limit = (before.median_ns as f64 * (1.0 + threshold))
+                .max((before.median_ns + before.stddev_ns + after.stddev_ns) as f64);
+            (after.median_ns as f64 > limit).then(|| Regression {
+                stage,
+                before: before.median(),
+                after: after.median(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    measurements: Vec<Measurement>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let measurements = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                serde_json::from_str(&contents).map_err(|source| AocError::CacheFormat {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(AocError::cache_io(&path)(err)),
+        };
+        Ok(History { path, measurements })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn measurements(&self) -> &[Measurement] {
+        &self.measurements
+    }
+
+    /// The latest earlier measurement of the same day and input.
+    pub fn previous(&self, measurement: &Measurement) -> Option<&Measurement> {
+        self.measurements.iter().rev().find(|earlier| {
+            earlier.year == measurement.year
+                && earlier.day == measurement.day
+                && earlier.input_hash == measurement.input_hash
+        })
+    }
+
+    pub fn record(&mut self, measurements: Vec<Measurement>) -> Result<(), AocError> {
+        // Keeps the measurements other processes recorded since this history was loaded.
+        self.measurements = update_json(&self.path, |recorded: &mut Vec<Measurement>| {
+            recorded.extend(measurements)
+        })?;
+        Ok(())
+    }
+}
+
+/// Renders the median and standard deviation of each stage as a table.
+pub fn table(measurements: &[Measurement]) -> String {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+    ]];
+    let cell = |stats: Option<Stats>| match stats {
+        Some(stats) => format!(
+            "{} ± {}",
+            format_duration(stats.median()),
+            format_duration(stats.stddev())
+        ),
+        None => "-".to_string(),
+    };
+    for measurement in measurements {
+        rows.push([
+            format!("{} {:02}", measurement.year, measurement.day),
+            cell(Some(measurement.parse)),
+            cell(measurement.part1),
+            cell(measurement.part2),
+        ]);
+    }
+
+    render_table(&rows, |column| column > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn measurement(input_hash: &str, parse: u64, part1: u64) -> Measurement {
+        let stats = |median_ns| Stats {
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 10,
+        };
+        Measurement {
+            year: 2024,
+            day: 16,
+            input_hash: input_hash.to_string(),
+            timestamp: 0,
+            samples: 20,
+            parse: stats(parse),
+            part1: Some(stats(part1)),
+            part2: None,
+        }
+    }
+
+    #[rstest]
+    #[case(&[], (0, 0, 0, 0))]
+    #[case(&[5], (5, 5, 5, 0))]
+    #[case(&[4, 1, 3, 2], (1, 2, 3, 1))]
+    #[case(&[2, 4, 4, 4, 5, 5, 7, 9], (2, 4, 5, 2))]
+    fn stats_test(#[case] samples: &[u64], #[case] expected: (u64, u64, u64, u64)) {
+        let samples: Vec<Duration> = samples.iter().map(|&ns| Duration::from_nanos(ns)).collect();
+        let stats = Stats::new(&samples);
+        assert_eq!(
+            (
+                stats.min_ns,
+                stats.median_ns,
+                stats.mean_ns,
+                stats.stddev_ns
+            ),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(1_000, 1_050, vec![])]
+    #[case(1_000, 1_200, vec!["part 1"])]
+    #[case(10, 15, vec![])]
+    fn regressions_test(#[case] before: u64, #[case] after: u64, #[case] expected: Vec<&str>) {
+        let previous = measurement("abc", 500, before);
+        let current = measurement("abc", 500, after);
+        let stages: Vec<&str> = regressions(&previous, &current, REGRESSION_THRESHOLD)
+            .iter()
+            .map(|regression| regression.stage)
+            .collect();
+        assert_eq!(stages, expected);
+    }
+
+    #[rstest]
+    fn history_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bench").join(HISTORY_FILE);
+
+        let mut history = History::load(&path).unwrap();
+        history
+            .record(vec![measurement("abc", 1, 2), measurement("def", 3, 4)])
+            .unwrap();
+        // Another process recording in the meantime.
+        History::load(&path)
+            .unwrap()
+            .record(vec![measurement("ghi", 7, 8)])
+            .unwrap();
+        history.record(vec![measurement("abc", 5, 6)]).unwrap();
+        assert_eq!(history.measurements().len(), 4);
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.measurements().len(), 4);
+        let previous = history.previous(&measurement("abc", 0, 0)).unwrap();
+        assert_eq!(previous.parse.median_ns, 5);
+        assert!(history.previous(&measurement("xyz", 0, 0)).is_none());
+    }
+
+    #[rstest]
+    fn table_test() {
+        assert_eq!(
+            table(&[measurement("abc", 1_500, 20)]),
+            "Day     | Parse        | Part 1      | Part 2\n\
+             --------+--------------+-------------+-------\n\
+             2024 16 | 1.5µs ± 10ns | 20ns ± 10ns |      -\n"
+        );
+    }
+}
@@ -0,0 +1,658 @@
+use crate::config::Config;
+use crate::profile::{selected_profile, DEFAULT_PROFILE};
+use crate::throttle::open_locked;
+use crate::{AocError, Fetcher, Part, PuzzlePage};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs, io};
+
+pub const DEFAULT_CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/cache");
+const CACHE_DIR_ENV_VAR: &str = "AOC_CACHE_DIR";
+const DEFAULT_ANSWERS_TTL: Duration = Duration::from_secs(15 * 60);
+const ANSWERS_TTL_ENV_VAR: &str = "AOC_ANSWERS_TTL";
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Numbers the temporary files of this process, see [`write_atomic`].
+static TEMP_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// Bodies AoC serves in place of an input, e.g. when not logged in.
+const ERROR_PAGE_MARKERS: [&str; 4] = [
+    "Please log in",
+    "Please don't repeatedly request this endpoint",
+    "404 Not Found",
+    "500 Internal Server Error",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedAnswers {
+    pub fetched_at: u64,
+    pub stars: u8,
+    pub answers: Vec<String>,
+}
+
+impl CachedAnswers {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers.get(part.index()).map(String::as_str)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.stars == 2
+    }
+
+    /// Complete answers never change, so only incomplete ones expire.
+    fn is_fresh(&self, ttl: Duration, now: u64) -> bool {
+        self.is_complete() || now.saturating_sub(self.fetched_at) < ttl.as_secs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputEntry {
+    pub sha256: String,
+    pub size: u64,
+    pub fetched_at: u64,
+    pub account: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Keyed by `{year}/{day:02}`.
+    pub inputs: BTreeMap<String, InputEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheIssue {
+    Unrecorded { path: PathBuf },
+    Missing { key: String },
+    HashMismatch { path: PathBuf },
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl Display for CacheIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheIssue::Unrecorded { path } => {
+                write!(f, "{} isn't recorded in the manifest", path.display())
+            }
+            CacheIssue::Missing { key } => {
+                write!(
+                    f,
+                    "The manifest records input {key}, but its file is missing"
+                )
+            }
+            CacheIssue::HashMismatch { path } => {
+                write!(f, "{} was modified after it was fetched", path.display())
+            }
+            CacheIssue::Invalid { path, reason } => write!(f, "{}: {reason}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    answers_ttl: Duration,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache {
+            dir: dir.into(),
+            answers_ttl: DEFAULT_ANSWERS_TTL,
+        }
+    }
+
+    /// The cache root comes from `AOC_CACHE_DIR`, the config file or the default, in this order.
+    /// Named profiles get their own namespace below it. The answers TTL comes from
+    /// `AOC_ANSWERS_TTL` (in seconds).
+    pub fn from_config(config: &Config, profile: &str) -> Self {
+        let root = Cache::root(config);
+        let dir = match profile {
+            DEFAULT_PROFILE => root,
+            profile => root.join("profiles").join(profile),
+        };
+
+        let cache = Cache::new(dir);
+        match env::var(ANSWERS_TTL_ENV_VAR)
+            .ok()
+            .and_then(|ttl| ttl.parse().ok())
+        {
+            Some(ttl) => cache.with_answers_ttl(Duration::from_secs(ttl)),
+            None => cache,
+        }
+    }
+
+    /// The directory shared by all profiles.
+    pub fn root(config: &Config) -> PathBuf {
+        match env::var_os(CACHE_DIR_ENV_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => config
+                .cache_dir
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_DIR)),
+        }
+    }
+
+    /// The cache of the profile selected by `AOC_PROFILE`.
+    pub fn from_env() -> Result<Self, AocError> {
+        Ok(Cache::from_config(&Config::load()?, &selected_profile()))
+    }
+
+    pub fn with_answers_ttl(mut self, answers_ttl: Duration) -> Self {
+        self.answers_ttl = answers_ttl;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn year_dir(&self, year: u32) -> PathBuf {
+        self.dir.join(year.to_string())
+    }
+
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.year_dir(year).join(format!("{day:02}-input.txt"))
+    }
+
+    pub fn answers_path(&self, year: u32, day: u32) -> PathBuf {
+        self.year_dir(year).join(format!("{day:02}-answers.json"))
+    }
+
+    pub fn submissions_path(&self, year: u32, day: u32) -> PathBuf {
+        self.year_dir(year)
+            .join(format!("{day:02}-submissions.json"))
+    }
+
+    pub fn manifest_path(&self) -> PathBuf {
+        self.dir.join(MANIFEST_FILE)
+    }
+
+    // Before the per-year layout, everything was cached as `{year}-{day}-*.txt` in the root.
+    fn legacy_path(&self, year: u32, day: u32, kind: &str) -> PathBuf {
+        self.dir.join(format!("{year}-{day}-{kind}.txt"))
+    }
+
+    pub fn input_file(&self, fetcher: &Fetcher, year: u32, day: u32) -> Result<PathBuf, AocError> {
+        let cache_file = self.input_path(year, day);
+
+        if cache_file.exists() {
+            return Ok(cache_file);
+        }
+
+        let legacy_file = self.legacy_path(year, day, "input");
+        let (contents, account) = match read_optional(&legacy_file)? {
+            Some(contents) => (contents, None),
+            None => {
+                let contents = fetcher.fetch_input(year, day)?;
+                (contents, Some(fetcher.session()?.fingerprint()))
+            }
+        };
+
+        check_input(&contents).map_err(|reason| AocError::InvalidInput { year, day, reason })?;
+        self.write(&cache_file, &contents)?;
+        self.record_input(year, day, &contents, account)?;
+        remove_optional(&legacy_file)?;
+
+        Ok(cache_file)
+    }
+
+    /// Never touches the network. Tries `input.txt` in `src_dir`, the cache and then, only if
+    /// `examples` is set, the examples in `src_dir`.
+    pub fn offline_input_file(
+        &self,
+        year: u32,
+        day: u32,
+        src_dir: &Path,
+        examples: bool,
+    ) -> Result<PathBuf, AocError> {
+        let mut candidates = vec![
+            src_dir.join("input.txt"),
+            self.input_path(year, day),
+            self.legacy_path(year, day, "input"),
+        ];
+        if examples {
+            candidates.push(src_dir.join("test_input.txt"));
+            candidates.push(src_dir.join("test_input1.txt"));
+        }
+
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(AocError::Offline {
+                year,
+                day,
+                kind: "input",
+                tried: candidates,
+            }),
+        }
+    }
+
+    pub fn answers(
+        &self,
+        fetcher: &Fetcher,
+        year: u32,
+        day: u32,
+    ) -> Result<CachedAnswers, AocError> {
+        if let Some(cached) = self.cached_answers(year, day)? {
+            if cached.is_fresh(self.answers_ttl, unix_time()) {
+                return Ok(cached);
+            }
+        }
+
+        let contents = fetcher.fetch_problem(year, day)?;
+        let page = PuzzlePage::parse(&contents).map_err(|reason| AocError::PageParse {
+            year,
+            day,
+            reason,
+        })?;
+        self.store_answers(year, day, &page)
+    }
+
+    pub fn cached_answers(&self, year: u32, day: u32) -> Result<Option<CachedAnswers>, AocError> {
+        let cache_file = self.answers_path(year, day);
+        if let Some(contents) = read_optional(&cache_file)? {
+            return read_json(&cache_file, &contents).map(Some);
+        }
+
+        // Legacy answers were only cached once both parts were complete.
+        let legacy_file = self.legacy_path(year, day, "answers");
+        Ok(read_optional(&legacy_file)?.map(|contents| CachedAnswers {
+            fetched_at: 0,
+            stars: 2,
+            answers: contents.lines().map(str::to_string).collect(),
+        }))
+    }
+
+    pub fn store_answers(
+        &self,
+        year: u32,
+        day: u32,
+        page: &PuzzlePage,
+    ) -> Result<CachedAnswers, AocError> {
+        let cached = CachedAnswers {
+            fetched_at: unix_time(),
+            stars: page.stars,
+            answers: page.answers.clone(),
+        };
+
+        let cache_file = self.answers_path(year, day);
+        self.write(&cache_file, to_json(&cache_file, &cached)?)?;
+
+        Ok(cached)
+    }
+
+    /// Forgets the cached answers, so the next lookup fetches the puzzle page again.
+    pub fn invalidate(&self, year: u32, day: u32) -> Result<(), AocError> {
+        remove_optional(&self.answers_path(year, day))?;
+        remove_optional(&self.legacy_path(year, day, "answers"))
+    }
+
+    pub fn manifest(&self) -> Result<Manifest, AocError> {
+        let manifest_file = self.manifest_path();
+        match read_optional(&manifest_file)? {
+            Some(contents) => read_json(&manifest_file, &contents),
+            None => Ok(Manifest::default()),
+        }
+    }
+
+    fn record_input(
+        &self,
+        year: u32,
+        day: u32,
+        contents: &str,
+        account: Option<String>,
+    ) -> Result<(), AocError> {
+        // Parallel builds record inputs from several processes.
+        update_json(&self.manifest_path(), |manifest: &mut Manifest| {
+            manifest.inputs.insert(
+                manifest_key(year, day),
+                InputEntry {
+                    sha256: sha256(contents.as_bytes()),
+                    size: contents.len() as u64,
+                    fetched_at: unix_time(),
+                    account,
+                },
+            );
+        })?;
+        Ok(())
+    }
+
+    /// Checks every cached input against the manifest and for signs of a bad download.
+    pub fn verify(&self) -> Result<Vec<CacheIssue>, AocError> {
+        let manifest = self.manifest()?;
+        let mut issues = Vec::new();
+
+        for (key, entry) in &manifest.inputs {
+            let Some((year, day)) = parse_manifest_key(key) else {
+                issues.push(CacheIssue::Missing { key: key.clone() });
+                continue;
+            };
+            let path = self.input_path(year, day);
+            let Some(contents) = read_optional(&path)? else {
+                issues.push(CacheIssue::Missing { key: key.clone() });
+                continue;
+            };
+            if let Err(reason) = check_input(&contents) {
+                issues.push(CacheIssue::Invalid { path, reason });
+            } else if sha256(contents.as_bytes()) != entry.sha256 {
+                issues.push(CacheIssue::HashMismatch { path });
+            }
+        }
+
+        for year_dir in read_dir_sorted(&self.dir)? {
+            let Some(year) = file_name(&year_dir).and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            for path in read_dir_sorted(&year_dir)? {
+                let Some(day) = file_name(&path)
+                    .and_then(|name| name.strip_suffix("-input.txt"))
+                    .and_then(|day| day.parse::<u32>().ok())
+                else {
+                    continue;
+                };
+                if manifest.inputs.contains_key(&manifest_key(year, day)) {
+                    continue;
+                }
+                let contents = fs::read_to_string(&path).map_err(AocError::cache_io(&path))?;
+                match check_input(&contents) {
+                    Ok(()) => issues.push(CacheIssue::Unrecorded { path }),
+                    Err(reason) => issues.push(CacheIssue::Invalid { path, reason }),
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    fn write(&self, cache_file: &Path, contents: impl AsRef<[u8]>) -> Result<(), AocError> {
+        write_atomic(cache_file, contents)
+    }
+}
+
+/// Writes through a temporary file, so readers never see a partially written file. Its name is
+/// unique, so concurrent writes of the same file don't write to the same temporary file.
+pub(crate) fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), AocError> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(dir).map_err(AocError::cache_io(dir))?;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp_file = dir.join(format!(
+        ".{file_name}.{}.{}.tmp",
+        process::id(),
+        TEMP_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&temp_file, contents).map_err(AocError::cache_io(&temp_file))?;
+    fs::rename(&temp_file, path).map_err(AocError::cache_io(path))
+}
+
+/// Rereads, updates and replaces the JSON file at `path` while holding the `.lock` file next to
+/// it, so updates from other processes since it was last read aren't lost.
+pub(crate) fn update_json<T>(path: &Path, update: impl FnOnce(&mut T)) -> Result<T, AocError>
+where
+    T: Default + Serialize + for<'de> Deserialize<'de>,
+{
+    let _lock = open_locked(&path.with_extension("lock"))?;
+    let mut value = match read_optional(path)? {
+        Some(contents) => read_json(path, &contents)?,
+        None => T::default(),
+    };
+    update(&mut value);
+    write_atomic(path, to_json(path, &value)?)?;
+    Ok(value)
+}
+
+/// Catches error pages and truncated downloads before they end up in the cache.
+pub fn check_input(contents: &str) -> Result<(), String> {
+    if contents.is_empty() {
+        return Err("input is empty".to_string());
+    }
+    let start = contents.trim_start();
+    if start.starts_with("<!DOCTYPE") || start.starts_with("<html") {
+        return Err("input is an HTML page".to_string());
+    }
+    if let Some(marker) = ERROR_PAGE_MARKERS
+        .iter()
+        .find(|marker| contents.contains(*marker))
+    {
+        return Err(format!("input looks like an error page ({marker:?})"));
+    }
+    if !contents.ends_with('\n') {
+        return Err("input doesn't end with a newline and is probably truncated".to_string());
+    }
+    Ok(())
+}
+
+fn manifest_key(year: u32, day: u32) -> String {
+    format!("{year}/{day:02}")
+}
+
+fn parse_manifest_key(key: &str) -> Option<(u32, u32)> {
+    let (year, day) = key.split_once('/')?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+pub(crate) fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn file_name(path: &Path) -> Option<&str> {
+    path.file_name()?.to_str()
+}
+
+fn read_dir_sorted(dir: &Path) -> Result<Vec<PathBuf>, AocError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(AocError::cache_io(dir)(err)),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(AocError::cache_io(dir))?;
+    paths.sort();
+    Ok(paths)
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, AocError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(AocError::cache_io(path)(err)),
+    }
+}
+
+fn remove_optional(path: &Path) -> Result<(), AocError> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(AocError::cache_io(path)(err)),
+        _ => Ok(()),
+    }
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path, contents: &str) -> Result<T, AocError> {
+    serde_json::from_str(contents).map_err(|source| AocError::CacheFormat {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn to_json(path: &Path, value: &impl Serialize) -> Result<String, AocError> {
+    serde_json::to_string_pretty(value).map_err(|source| AocError::CacheFormat {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+pub(crate) fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(2, 0, true)]
+    #[case(1, 1_000, true)]
+    #[case(1, 901, true)]
+    #[case(1, 900, false)]
+    #[case(0, 0, false)]
+    fn is_fresh_test(#[case] stars: u8, #[case] fetched_at: u64, #[case] expected: bool) {
+        let cached = CachedAnswers {
+            fetched_at,
+            stars,
+            answers: Vec::new(),
+        };
+        assert_eq!(cached.is_fresh(Duration::from_secs(100), 1_000), expected);
+    }
+
+    #[rstest]
+    fn legacy_answers_are_complete() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("2023-25-answers.txt"), "54\n").unwrap();
+
+        let cache = Cache::new(dir.path());
+        let cached = cache.cached_answers(2023, 25).unwrap().unwrap();
+        assert!(cached.is_complete());
+        assert_eq!(cached.answer(Part::One), Some("54"));
+        assert_eq!(cached.answer(Part::Two), None);
+
+        cache.invalidate(2023, 25).unwrap();
+        assert_eq!(cache.cached_answers(2023, 25).unwrap(), None);
+    }
+
+    #[rstest]
+    fn offline_input_fallbacks() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let src_dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(cache_dir.path());
+
+        let err = cache
+            .offline_input_file(2024, 7, src_dir.path(), true)
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            AocError::Offline { tried, .. } if tried.contains(&cache.input_path(2024, 7))
+        ));
+
+        fs::write(src_dir.path().join("test_input1.txt"), "1\n").unwrap();
+        fs::write(src_dir.path().join("test_input.txt"), "2\n").unwrap();
+        // The examples are only used when asked for.
+        assert!(cache
+            .offline_input_file(2024, 7, src_dir.path(), false)
+            .is_err());
+        assert_eq!(
+            cache
+                .offline_input_file(2024, 7, src_dir.path(), true)
+                .unwrap(),
+            src_dir.path().join("test_input.txt")
+        );
+
+        cache.write(&cache.input_path(2024, 7), "3\n").unwrap();
+        assert_eq!(
+            cache
+                .offline_input_file(2024, 7, src_dir.path(), true)
+                .unwrap(),
+            cache.input_path(2024, 7)
+        );
+
+        fs::write(src_dir.path().join("input.txt"), "4\n").unwrap();
+        assert_eq!(
+            cache
+                .offline_input_file(2024, 7, src_dir.path(), true)
+                .unwrap(),
+            src_dir.path().join("input.txt")
+        );
+    }
+
+    #[rstest]
+    #[case("1 2\n3 4\n", true)]
+    #[case("", false)]
+    #[case("1 2\n3", false)]
+    #[case(
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        false
+    )]
+    #[case("<!DOCTYPE html>\n<html lang=\"en-us\">\n", false)]
+    fn check_input_test(#[case] contents: &str, #[case] valid: bool) {
+        assert_eq!(check_input(contents).is_ok(), valid);
+    }
+
+    #[rstest]
+    fn concurrent_records_are_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+
+        std::thread::scope(|scope| {
+            for day in 1..=10 {
+                let cache = &cache;
+                scope.spawn(move || cache.record_input(2024, day, "1 2\n", None).unwrap());
+            }
+        });
+        assert_eq!(cache.manifest().unwrap().inputs.len(), 10);
+        let leftovers: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| name.to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty(), "{leftovers:?}");
+    }
+
+    #[rstest]
+    fn verify_finds_bad_inputs() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Cache::new(dir.path());
+
+        for (day, contents) in [(1, "1 2\n"), (2, "3 4\n"), (3, "5 6\n")] {
+            cache.write(&cache.input_path(2024, day), contents).unwrap();
+            cache.record_input(2024, day, contents, None).unwrap();
+        }
+        cache.write(&cache.input_path(2024, 2), "3 5\n").unwrap();
+        fs::remove_file(cache.input_path(2024, 3)).unwrap();
+        cache.write(&cache.input_path(2024, 4), "7 8\n").unwrap();
+        cache.write(&cache.input_path(2024, 5), "7 8").unwrap();
+
+        let issues = cache.verify().unwrap();
+        assert_eq!(issues.len(), 4);
+        assert_eq!(
+            issues[0],
+            CacheIssue::HashMismatch {
+                path: cache.input_path(2024, 2)
+            }
+        );
+        assert_eq!(
+            issues[1],
+            CacheIssue::Missing {
+                key: "2024/03".to_string()
+            }
+        );
+        assert_eq!(
+            issues[2],
+            CacheIssue::Unrecorded {
+                path: cache.input_path(2024, 4)
+            }
+        );
+        assert!(
+            matches!(&issues[3], CacheIssue::Invalid { path, .. } if *path == cache.input_path(2024, 5))
+        );
+    }
+
+    #[rstest]
+    fn manifest_key_round_trip() {
+        assert_eq!(manifest_key(2023, 7), "2023/07");
+        assert_eq!(parse_manifest_key("2023/07"), Some((2023, 7)));
+        assert_eq!(parse_manifest_key("latest"), None);
+    }
+}
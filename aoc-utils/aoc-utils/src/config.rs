@@ -0,0 +1,85 @@
+use crate::session::Browser;
+use crate::AocError;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+const CONFIG_ENV_VAR: &str = "AOC_CONFIG";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub cache_dir: Option<PathBuf>,
+    pub profiles: BTreeMap<String, ProfileConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProfileConfig {
+    pub session_env: Option<String>,
+    pub session_file: Option<PathBuf>,
+    pub browser: Option<Browser>,
+}
+
+impl Config {
+    /// `$XDG_CONFIG_HOME/aoc`, falling back to `~/.config/aoc`.
+    pub fn dir() -> Option<PathBuf> {
+        let config_dir = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("aoc"))
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        env::var_os(CONFIG_ENV_VAR)
+            .map(PathBuf::from)
+            .or_else(|| Config::dir().map(|dir| dir.join("config.toml")))
+    }
+
+    pub fn load() -> Result<Config, AocError> {
+        let Some(path) = Config::path() else {
+            return Ok(Config::default());
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Config::parse(&contents).map_err(|source| AocError::ConfigFormat { path, source })
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(source) => Err(AocError::ConfigIo { path, source }),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn parse_profiles() {
+        let config = Config::parse(
+            "[profiles.alice]\nsession_env = \"ALICE_SESSION\"\n\n[profiles.bob]\nbrowser = \"chrome\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.profiles["alice"].session_env.as_deref(),
+            Some("ALICE_SESSION")
+        );
+        assert_eq!(config.profiles["bob"].browser, Some(Browser::Chrome));
+        assert!(Config::parse("[profiles.carol]\nbrowser = \"netscape\"\n").is_err());
+    }
+
+    #[rstest]
+    fn parse_cache_dir() {
+        let config = Config::parse("cache_dir = \"/tmp/aoc\"\n").unwrap();
+        assert_eq!(config.cache_dir, Some(PathBuf::from("/tmp/aoc")));
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert!(Config::parse("cache = 1").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence `x0, step(x0), step(step(x0)), ...` starts repeating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first state that repeats.
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest index whose state equals the state at index `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle with Brent's algorithm, which keeps only two states around and so works for
+/// states that can't be hashed or are too big to remember.
+///
+/// Never returns if the sequence doesn't repeat.
+pub fn find_cycle<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, both meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state, which takes one step per state and also returns
+/// them all, from the initial state up to the end of the first loop.
+pub fn find_cycle_hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return (cycle, states);
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+/// The state after `n` steps, skipping ahead as soon as the states repeat.
+pub fn state_after<S: Clone + Eq + Hash>(initial: S, n: usize, mut step: impl FnMut(&S) -> S) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return states.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    /// 3 steps into a loop of 4: 0 1 2 3 4 5 6 3 4 5 6 ...
+    fn step(&n: &u32) -> u32 {
+        if n == 6 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[rstest]
+    fn find() {
+        let expected = Cycle {
+            start: 3,
+            length: 4,
+        };
+        assert_eq!(find_cycle(0, step), expected);
+
+        let (cycle, states) = find_cycle_hashed(0, step);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, [0, 1, 2, 3, 4, 5, 6]);
+
+        let pure_loop = Cycle {
+            start: 0,
+            length: 5,
+        };
+        assert_eq!(find_cycle(0, |&n| (n + 1) % 5), pure_loop);
+        assert_eq!(find_cycle_hashed(0, |&n| (n + 1) % 5).0, pure_loop);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(6, 6)]
+    #[case(7, 3)]
+    #[case(1_000_000_000, 4)]
+    fn after(#[case] n: usize, #[case] expected: u32) {
+        assert_eq!(state_after(0, n, step), expected);
+        let cycle = find_cycle(0, step);
+        assert_eq!(find_cycle_hashed(0, step).1[cycle.reduce(n)], expected);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::point::Point2;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The orthogonal directions, with `y` growing downwards like in the puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// The orthogonal and diagonal directions, with `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir4 {
+    /// Clockwise, starting upwards.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+
+    /// Only the `^>v<` notation, unlike `TryFrom<char>`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        Dir4::ALL.into_iter().find(|dir| dir.arrow() == c)
+    }
+}
+
+impl Dir8 {
+    /// Clockwise, starting north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Turns by 45 degrees.
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45 degrees.
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir8::North => (0, -1),
+            Dir8::NorthEast => (1, -1),
+            Dir8::East => (1, 0),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (0, 1),
+            Dir8::SouthWest => (-1, 1),
+            Dir8::West => (-1, 0),
+            Dir8::NorthWest => (-1, -1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Dir8::North => "N",
+            Dir8::NorthEast => "NE",
+            Dir8::East => "E",
+            Dir8::SouthEast => "SE",
+            Dir8::South => "S",
+            Dir8::SouthWest => "SW",
+            Dir8::West => "W",
+            Dir8::NorthWest => "NW",
+        }
+    }
+}
+
+/// Accepts the `^>v<`, `URDL` and `NESW` notations.
+impl TryFrom<char> for Dir4 {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Dir4::Up),
+            '>' | 'R' | 'E' => Ok(Dir4::Right),
+            'v' | 'D' | 'S' => Ok(Dir4::Down),
+            '<' | 'L' | 'W' => Ok(Dir4::Left),
+            _ => Err(format!("{c:?} isn't a direction")),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(format!("{s:?} isn't a direction")),
+        }
+    }
+}
+
+/// Accepts `N`, `NE`, `E`, ... as well as the notations of [`Dir4`].
+impl FromStr for Dir8 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        if let Some(dir) = Dir8::ALL.into_iter().find(|dir| dir.abbreviation() == s) {
+            return Ok(dir);
+        }
+        s.parse::<Dir4>().map(Dir8::from)
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::North,
+            Dir4::Right => Dir8::East,
+            Dir4::Down => Dir8::South,
+            Dir4::Left => Dir8::West,
+        }
+    }
+}
+
+impl From<Dir4> for Point2<isize> {
+    fn from(dir: Dir4) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Dir8> for Point2<isize> {
+    fn from(dir: Dir8) -> Self {
+        dir.offset()
+    }
+}
+
+impl Display for Dir4 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+impl Display for Dir8 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Dir4::Up, Dir4::Right, Dir4::Left, Dir4::Down)]
+    #[case(Dir4::Left, Dir4::Up, Dir4::Down, Dir4::Right)]
+    fn turns4(#[case] dir: Dir4, #[case] right: Dir4, #[case] left: Dir4, #[case] opposite: Dir4) {
+        assert_eq!(dir.turn_right(), right);
+        assert_eq!(dir.turn_left(), left);
+        assert_eq!(dir.opposite(), opposite);
+    }
+
+    #[rstest]
+    #[case(Dir8::North, Dir8::NorthEast, Dir8::NorthWest, Dir8::South)]
+    #[case(Dir8::NorthWest, Dir8::North, Dir8::West, Dir8::SouthEast)]
+    fn turns8(#[case] dir: Dir8, #[case] right: Dir8, #[case] left: Dir8, #[case] opposite: Dir8) {
+        assert_eq!(dir.turn_right(), right);
+        assert_eq!(dir.turn_left(), left);
+        assert_eq!(dir.opposite(), opposite);
+    }
+
+    #[rstest]
+    fn offsets() {
+        for dir in Dir4::ALL {
+            assert_eq!(
+                dir.offset::<i32>() + dir.opposite().offset(),
+                Point2::new(0, 0)
+            );
+            assert_eq!(Dir8::from(dir).offset::<i32>(), dir.offset());
+        }
+        assert_eq!(Dir8::SouthWest.offset::<i64>(), Point2::new(-1, 1));
+        assert!(Dir8::SouthWest.is_diagonal() && !Dir8::South.is_diagonal());
+        assert!(Dir4::Left.is_horizontal() && Dir4::Up.is_vertical());
+    }
+
+    #[rstest]
+    #[case("^", Ok(Dir4::Up))]
+    #[case("R", Ok(Dir4::Right))]
+    #[case("S", Ok(Dir4::Down))]
+    #[case("<", Ok(Dir4::Left))]
+    #[case("x", Err(()))]
+    #[case("UD", Err(()))]
+    fn parse4(#[case] s: &str, #[case] expected: Result<Dir4, ()>) {
+        assert_eq!(s.parse::<Dir4>().map_err(|_| ()), expected);
+    }
+
+    #[rstest]
+    #[case('^', Some(Dir4::Up))]
+    #[case('<', Some(Dir4::Left))]
+    #[case('U', None)]
+    #[case('S', None)]
+    fn from_arrow(#[case] c: char, #[case] expected: Option<Dir4>) {
+        assert_eq!(Dir4::from_arrow(c), expected);
+    }
+
+    #[rstest]
+    #[case("NE", Ok(Dir8::NorthEast))]
+    #[case("W", Ok(Dir8::West))]
+    #[case("v", Ok(Dir8::South))]
+    #[case("NNE", Err(()))]
+    fn parse8(#[case] s: &str, #[case] expected: Result<Dir8, ()>) {
+        assert_eq!(s.parse::<Dir8>().map_err(|_| ()), expected);
+    }
+
+    #[rstest]
+    fn display() {
+        assert_eq!(Dir4::Down.to_string(), "v");
+        assert_eq!(Dir8::SouthEast.to_string(), "SE");
+    }
+}
@@ -0,0 +1,223 @@
+use reqwest::StatusCode;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum AocError {
+    SessionMissing {
+        attempts: Vec<String>,
+        source: Option<Box<dyn Error + Send + Sync>>,
+    },
+    InvalidSession {
+        provider: String,
+        reason: String,
+    },
+    Http {
+        url: String,
+        status: StatusCode,
+        body: String,
+    },
+    Client {
+        source: reqwest::Error,
+    },
+    Network {
+        url: String,
+        source: reqwest::Error,
+    },
+    CacheIo {
+        path: PathBuf,
+        source: io::Error,
+    },
+    CacheFormat {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    ConfigIo {
+        path: PathBuf,
+        source: io::Error,
+    },
+    InputIo {
+        path: PathBuf,
+        source: io::Error,
+    },
+    ConfigFormat {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    InvalidInput {
+        year: u32,
+        day: u32,
+        reason: String,
+    },
+    UnknownProfile {
+        name: String,
+        known: Vec<String>,
+    },
+    InvalidProfileName {
+        name: String,
+    },
+    PageParse {
+        year: u32,
+        day: u32,
+        reason: String,
+    },
+    SubmissionRefused {
+        answer: String,
+        reason: String,
+    },
+    Offline {
+        year: u32,
+        day: u32,
+        kind: &'static str,
+        tried: Vec<PathBuf>,
+    },
+}
+
+impl AocError {
+    pub(crate) fn cache_io(path: impl Into<PathBuf>) -> impl FnOnce(io::Error) -> Self {
+        let path = path.into();
+        move |source| AocError::CacheIo { path, source }
+    }
+
+    pub(crate) fn network(url: &str) -> impl FnOnce(reqwest::Error) -> Self + '_ {
+        move |source| AocError::Network {
+            url: url.to_string(),
+            source,
+        }
+    }
+
+    /// Renders the error together with its chain of sources.
+    pub fn report(&self) -> String {
+        let mut report = self.to_string();
+        let mut source = self.source();
+        if source.is_some() {
+            report.push_str("\n\nCaused by:");
+        }
+        while let Some(err) = source {
+            report.push_str(&format!("\n    {err}"));
+            source = err.source();
+        }
+        report
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::SessionMissing { attempts, .. } => {
+                write!(f, "No Advent of Code session found")?;
+                for attempt in attempts {
+                    write!(f, "\n  - {attempt}")?;
+                }
+                write!(
+                    f,
+                    "\nSet AOC_SESSION, write the token to ~/.config/aoc/session or log in to adventofcode.com in a browser."
+                )
+            }
+            AocError::InvalidSession { provider, reason } => {
+                write!(f, "Invalid session token from {provider}: {reason}")
+            }
+            AocError::Http { url, status, body } => {
+                write!(f, "Request to {url} failed with status {status}")?;
+                let body = body.trim();
+                if !body.is_empty() {
+                    const MAX_BODY: usize = 200;
+                    match body.char_indices().nth(MAX_BODY) {
+                        Some((end, _)) => write!(f, ": {}...", &body[..end])?,
+                        None => write!(f, ": {body}")?,
+                    }
+                }
+                Ok(())
+            }
+            AocError::Client { .. } => write!(f, "Failed to build the HTTP client"),
+            AocError::Network { url, .. } => write!(f, "Failed to send request to {url}"),
+            AocError::CacheIo { path, .. } => {
+                write!(f, "Failed to access cache file {}", path.display())
+            }
+            AocError::CacheFormat { path, .. } => {
+                write!(f, "Cache file {} is malformed", path.display())
+            }
+            AocError::ConfigIo { path, .. } => {
+                write!(f, "Failed to read config file {}", path.display())
+            }
+            AocError::ConfigFormat { path, .. } => {
+                write!(f, "Config file {} is malformed", path.display())
+            }
+            AocError::InputIo { path, .. } => {
+                write!(f, "Failed to read input file {}", path.display())
+            }
+            AocError::InvalidInput { year, day, reason } => {
+                write!(
+                    f,
+                    "Refusing to cache the input for {year} day {day}: {reason}"
+                )
+            }
+            AocError::UnknownProfile { name, known } => {
+                write!(f, "Unknown profile {name:?}")?;
+                if !known.is_empty() {
+                    write!(f, ", the config file defines: {}", known.join(", "))?;
+                }
+                Ok(())
+            }
+            AocError::InvalidProfileName { name } => {
+                write!(
+                    f,
+                    "Invalid profile name {name:?}, use only letters, digits, '_' and '-'"
+                )
+            }
+            AocError::SubmissionRefused { answer, reason } => {
+                write!(f, "Refusing to submit {answer}: {reason}")
+            }
+            AocError::PageParse { year, day, reason } => {
+                write!(
+                    f,
+                    "Failed to parse puzzle page for {year} day {day}: {reason}"
+                )
+            }
+            AocError::Offline {
+                year,
+                day,
+                kind,
+                tried,
+            } => {
+                write!(f, "No {kind} for {year} day {day} available offline")?;
+                for path in tried {
+                    write!(f, "\n  - {} doesn't exist", path.display())?;
+                }
+                write!(f, "\nUnset AOC_OFFLINE to download it.")?;
+                if *kind == "input" && !tried.iter().any(|path| path.ends_with("test_input.txt")) {
+                    write!(
+                        f,
+                        " Set AOC_OFFLINE=examples to use the example input instead."
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::SessionMissing { source, .. } => source
+                .as_ref()
+                .map(|source| source.as_ref() as &(dyn Error + 'static)),
+            AocError::Client { source } | AocError::Network { source, .. } => Some(source),
+            AocError::CacheIo { source, .. } => Some(source),
+            AocError::CacheFormat { source, .. } => Some(source),
+            AocError::ConfigIo { source, .. } | AocError::InputIo { source, .. } => Some(source),
+            AocError::ConfigFormat { source, .. } => Some(source),
+            AocError::InvalidSession { .. }
+            | AocError::Http { .. }
+            | AocError::PageParse { .. }
+            | AocError::InvalidInput { .. }
+            | AocError::UnknownProfile { .. }
+            | AocError::InvalidProfileName { .. }
+            | AocError::SubmissionRefused { .. }
+            | AocError::Offline { .. } => None,
+        }
+    }
+}
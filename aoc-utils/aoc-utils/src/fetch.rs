@@ -0,0 +1,275 @@
+use crate::session::{Session, SessionChain};
+use crate::submit::Part;
+use crate::throttle::Throttle;
+use crate::AocError;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, thread};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/LeWimbes/advent-of-code aoc-utils/",
+    env!("CARGO_PKG_VERSION")
+);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+const MIN_INTERVAL_ENV_VAR: &str = "AOC_MIN_INTERVAL_MS";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Longer `Retry-After` delays fail the request instead of blocking it.
+    pub max_retry_after: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+pub struct FetcherBuilder {
+    base_url: String,
+    timeout: Duration,
+    user_agent: String,
+    retry: RetryPolicy,
+    throttle: Throttle,
+    sessions: SessionChain,
+}
+
+impl FetcherBuilder {
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn sessions(mut self, sessions: SessionChain) -> Self {
+        self.sessions = sessions;
+        self
+    }
+
+    /// Applies `AOC_BASE_URL` and `AOC_MIN_INTERVAL_MS` if they are set.
+    pub fn with_env(mut self) -> Self {
+        if let Ok(base_url) = env::var(BASE_URL_ENV_VAR) {
+            self = self.base_url(base_url);
+        }
+        if let Some(interval) = env::var(MIN_INTERVAL_ENV_VAR)
+            .ok()
+            .and_then(|interval| interval.parse().ok())
+        {
+            let throttle = Throttle::new(Duration::from_millis(interval));
+            self.throttle = match self.throttle.lock_file() {
+                Some(lock_file) => throttle.with_lock_file(lock_file),
+                None => throttle,
+            };
+        }
+        self
+    }
+
+    pub fn build(self) -> Result<Fetcher, AocError> {
+        let client = Client::builder()
+            .timeout(self.timeout)
+            .user_agent(self.user_agent)
+            .build()
+            .map_err(|source| AocError::Client { source })?;
+
+        Ok(Fetcher {
+            client,
+            base_url: self.base_url.trim_end_matches('/').to_string(),
+            retry: self.retry,
+            throttle: self.throttle,
+            sessions: self.sessions,
+            session: OnceLock::new(),
+        })
+    }
+}
+
+pub struct Fetcher {
+    client: Client,
+    base_url: String,
+    retry: RetryPolicy,
+    throttle: Throttle,
+    sessions: SessionChain,
+    session: OnceLock<Session>,
+}
+
+impl Fetcher {
+    pub fn builder() -> FetcherBuilder {
+        FetcherBuilder {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            retry: RetryPolicy::default(),
+            throttle: Throttle::default(),
+            sessions: SessionChain::default(),
+        }
+    }
+
+    pub fn from_env() -> Result<Fetcher, AocError> {
+        Fetcher::builder().with_env().build()
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn session(&self) -> Result<&Session, AocError> {
+        if let Some(session) = self.session.get() {
+            return Ok(session);
+        }
+        let session = self.sessions.resolve()?;
+        Ok(self.session.get_or_init(|| session))
+    }
+
+    pub fn fetch_problem(&self, year: u32, day: u32) -> Result<String, AocError> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    pub fn fetch_input(&self, year: u32, day: u32) -> Result<String, AocError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, AocError> {
+        let url = format!("{}{path}", self.base_url);
+        self.send(&url, &self.retry, || self.client.get(&url))
+    }
+
+    /// Never retried, so an answer can't be submitted twice by accident.
+    pub fn post_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        self.send(&url, &RetryPolicy::none(), || {
+            self.client
+                .post(&url)
+                .form(&[("level", level.as_str()), ("answer", answer)])
+        })
+    }
+
+    fn send(
+        &self,
+        url: &str,
+        retry: &RetryPolicy,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<String, AocError> {
+        let session = self.session()?;
+
+        let mut attempt = 0;
+        loop {
+            self.throttle.wait()?;
+            let result = request()
+                .header("Cookie", format!("session={}", session.token))
+                .send();
+
+            let retry_after = result.as_ref().ok().and_then(retry_after);
+            if let Some(delay) = retry_after {
+                // Other fetchers have to back off as well, even if this one gives up.
+                self.throttle.defer(delay)?;
+            }
+
+            let retryable = match &result {
+                Ok(response) => is_retryable(response.status()),
+                Err(err) => err.is_timeout() || err.is_connect(),
+            };
+            let delay = retry.backoff(attempt).max(retry_after.unwrap_or_default());
+            if retryable && attempt < retry.max_retries && delay <= retry.max_retry_after {
+                thread::sleep(delay);
+                attempt += 1;
+                continue;
+            }
+
+            return read_response(url, result.map_err(AocError::network(url))?);
+        }
+    }
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Only the delay-seconds form is supported; HTTP dates fall back to the backoff.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let seconds = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    seconds.trim().parse().ok().map(Duration::from_secs)
+}
+
+fn read_response(url: &str, response: Response) -> Result<String, AocError> {
+    let status = response.status();
+    if status != 200 {
+        let body = response.text().unwrap_or_default();
+        return Err(AocError::Http {
+            url: url.to_string(),
+            status,
+            body,
+        });
+    }
+
+    response.text().map_err(AocError::network(url))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, 500)]
+    #[case(1, 1000)]
+    #[case(3, 4000)]
+    #[case(10, 8000)]
+    fn backoff_test(#[case] attempt: u32, #[case] expected_ms: u64) {
+        let retry = RetryPolicy::default();
+        assert_eq!(retry.backoff(attempt), Duration::from_millis(expected_ms));
+    }
+}
@@ -0,0 +1,572 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A graph whose nodes are labeled, e.g. by the names in the input, and interned to `usize` ids in
+/// the order they're first seen. Unweighted edges get a weight of 1.
+#[derive(Debug, Clone)]
+pub struct Graph<L, W = u32> {
+    directed: bool,
+    labels: Vec<L>,
+    ids: HashMap<L, usize>,
+    adjacency: Vec<Vec<(usize, W)>>,
+    edge_count: usize,
+}
+
+impl<L: Clone + Eq + Hash, W: Copy> Graph<L, W> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Graph {
+            directed,
+            labels: Vec::new(),
+            ids: HashMap::new(),
+            adjacency: Vec::new(),
+            edge_count: 0,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// The id of the node labeled `label`, adding it if it's new.
+    pub fn node(&mut self, label: L) -> usize {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.ids.insert(label.clone(), id);
+        self.labels.push(label);
+        self.adjacency.push(Vec::new());
+        id
+    }
+
+    pub fn id(&self, label: &L) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: usize) -> &L {
+        &self.labels[id]
+    }
+
+    /// All labels, indexed by id.
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    pub fn add_weighted_edge(&mut self, from: L, to: L, weight: W) -> (usize, usize) {
+        let (from, to) = (self.node(from), self.node(to));
+        self.add_edge_between(from, to, weight);
+        (from, to)
+    }
+
+    pub fn add_edge_between(&mut self, from: usize, to: usize, weight: W) {
+        self.adjacency[from].push((to, weight));
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight));
+        }
+        self.edge_count += 1;
+    }
+
+    /// Removes one edge from `from` to `to`, returning whether there was one.
+    pub fn remove_edge_between(&mut self, from: usize, to: usize) -> bool {
+        let Some(i) = self.adjacency[from]
+            .iter()
+            .position(|&(next, _)| next == to)
+        else {
+            return false;
+        };
+        self.adjacency[from].swap_remove(i);
+        if !self.directed && from != to {
+            let i = self.adjacency[to]
+                .iter()
+                .position(|&(next, _)| next == from)
+                .unwrap();
+            self.adjacency[to].swap_remove(i);
+        }
+        self.edge_count -= 1;
+        true
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.adjacency[from].iter().any(|&(next, _)| next == to)
+    }
+
+    /// The outgoing edges of `id` with their weights.
+    pub fn edges(&self, id: usize) -> &[(usize, W)] {
+        &self.adjacency[id]
+    }
+
+    pub fn neighbors(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adjacency[id].iter().map(|&(next, _)| next)
+    }
+
+    /// An order in which every edge points forwards, or `None` if there's a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<usize>> {
+        if !self.directed {
+            return (self.edge_count == 0).then(|| (0..self.len()).collect());
+        }
+        let mut in_degrees = vec![0; self.len()];
+        for next in self.adjacency.iter().flatten().map(|&(next, _)| next) {
+            in_degrees[next] += 1;
+        }
+        let mut queue: VecDeque<usize> =
+            (0..self.len()).filter(|&id| in_degrees[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for next in self.neighbors(id) {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// The length of the longest path from `start` to `end` that visits no node twice.
+    ///
+    /// Acyclic graphs are solved in linear time, anything else by trying every path.
+    pub fn longest_path(&self, start: usize, end: usize) -> Option<W>
+    where
+        W: Ord + Add<Output = W> + Default,
+    {
+        let Some(order) = self.topological_sort() else {
+            return self.longest_simple_path(start, end);
+        };
+        let mut lengths: Vec<Option<W>> = vec![None; self.len()];
+        lengths[start] = Some(W::default());
+        for id in order {
+            let Some(length) = lengths[id] else {
+                continue;
+            };
+            for &(next, weight) in &self.adjacency[id] {
+                lengths[next] = lengths[next].max(Some(length + weight));
+            }
+        }
+        lengths[end]
+    }
+
+    /// The longest path by exhaustive search, which works for any graph but takes exponential time.
+    pub fn longest_simple_path(&self, start: usize, end: usize) -> Option<W>
+    where
+        W: Ord + Add<Output = W> + Default,
+    {
+        let mut longest = None;
+        let mut visited = vec![false; self.len()];
+        visited[start] = true;
+        // Each frame is a node on the current path, the next edge to try and the length so far.
+        let mut path = vec![(start, 0, W::default())];
+        while let Some(&(id, edge, length)) = path.last() {
+            if id == end {
+                longest = longest.max(Some(length));
+            } else if let Some(&(next, weight)) = self.adjacency[id].get(edge) {
+                path.last_mut().unwrap().1 += 1;
+                if !visited[next] {
+                    visited[next] = true;
+                    path.push((next, 0, length + weight));
+                }
+                continue;
+            }
+            visited[id] = false;
+            path.pop();
+        }
+        longest
+    }
+
+    /// The components when ignoring the direction of edges, each sorted by id.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut undirected = self.adjacency.clone();
+        if self.directed {
+            for (id, edges) in self.adjacency.iter().enumerate() {
+                for &(next, weight) in edges {
+                    undirected[next].push((id, weight));
+                }
+            }
+        }
+
+        let mut visited = vec![false; self.len()];
+        let mut components = Vec::new();
+        for root in 0..self.len() {
+            if visited[root] {
+                continue;
+            }
+            visited[root] = true;
+            let mut component = vec![root];
+            let mut stack = vec![root];
+            while let Some(id) = stack.pop() {
+                for &(next, _) in &undirected[id] {
+                    if !visited[next] {
+                        visited[next] = true;
+                        component.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component);
+        }
+        components
+    }
+
+    /// The strongly connected components using Tarjan's algorithm, in reverse topological order.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in 0..self.len() {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            // Simulates the recursion with the node and the next edge to follow.
+            let mut calls = vec![(root, 0)];
+            index[root] = next_index;
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some(&(id, edge)) = calls.last() {
+                if let Some(&(next, _)) = self.adjacency[id].get(edge) {
+                    calls.last_mut().unwrap().1 += 1;
+                    if index[next] == usize::MAX {
+                        index[next] = next_index;
+                        low_link[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    } else if on_stack[next] {
+                        low_link[id] = low_link[id].min(index[next]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low_link[parent] = low_link[parent].min(low_link[id]);
+                }
+                if low_link[id] == index[id] {
+                    let mut component = Vec::new();
+                    loop {
+                        let member = stack.pop().unwrap();
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// The cheapest set of edges to cut an undirected graph in two, using the
+    /// [Stoer–Wagner algorithm](https://en.wikipedia.org/wiki/Stoer–Wagner_algorithm).
+    ///
+    /// Returns the total weight of the cut and the nodes on one side of it.
+    pub fn min_cut(&self) -> (u64, Vec<usize>)
+    where
+        W: Into<u64>,
+    {
+        let n = self.len();
+        let mut matrix = vec![vec![0_i64; n]; n];
+        for (id, edges) in self.adjacency.iter().enumerate() {
+            for &(next, weight) in edges {
+                if id != next {
+                    matrix[id][next] += weight.into() as i64;
+                }
+            }
+        }
+
+        let mut best = (i64::MAX, Vec::new());
+        let mut merged: Vec<Vec<usize>> = (0..n).map(|id| vec![id]).collect();
+        for phase in 1..n {
+            let mut weights = matrix[0].clone();
+            let (mut s, mut t) = (0, 0);
+            for _ in 0..n - phase {
+                weights[t] = i64::MIN;
+                s = t;
+                t = (0..n).max_by_key(|&id| weights[id]).unwrap();
+                for (id, weight) in weights.iter_mut().enumerate() {
+                    *weight += matrix[t][id];
+                }
+            }
+
+            let side = merged[t].clone();
+            merged[s].extend(&side);
+            best = best.min((weights[t] - matrix[t][t], side));
+
+            let row: Vec<i64> = (0..n).map(|id| matrix[s][id] + matrix[t][id]).collect();
+            for (id, other) in matrix.iter_mut().enumerate() {
+                other[s] = row[id];
+            }
+            matrix[s] = row;
+            matrix[0][t] = i64::MIN;
+        }
+
+        let (cut, mut side) = best;
+        side.sort_unstable();
+        (cut.max(0) as u64, side)
+    }
+
+    /// The largest set of nodes that are all connected to each other, sorted by id, using the
+    /// Bron–Kerbosch algorithm with pivoting.
+    pub fn max_clique(&self) -> Vec<usize> {
+        let mut matrix = vec![vec![false; self.len()]; self.len()];
+        for (id, edges) in self.adjacency.iter().enumerate() {
+            for &(next, _) in edges {
+                matrix[id][next] = true;
+                matrix[next][id] = true;
+            }
+        }
+
+        let mut largest = Vec::new();
+        bron_kerbosch(
+            &matrix,
+            &mut Vec::new(),
+            (0..self.len()).collect(),
+            Vec::new(),
+            &mut largest,
+        );
+        largest.sort_unstable();
+        largest
+    }
+
+    /// Renders the graph in the DOT language of Graphviz, leaving out weights of 1.
+    pub fn to_dot(&self) -> String
+    where
+        L: Display,
+        W: Display + PartialEq + From<u8>,
+    {
+        let (keyword, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let mut dot = format!("{keyword} {{\n");
+        for label in &self.labels {
+            writeln!(dot, "    \"{label}\";").unwrap();
+        }
+        for (id, edges) in self.adjacency.iter().enumerate() {
+            for &(next, weight) in edges {
+                // Undirected edges are stored both ways but only drawn once.
+                if !self.directed && next < id {
+                    continue;
+                }
+                let (from, to) = (&self.labels[id], &self.labels[next]);
+                if weight == W::from(1) {
+                    writeln!(dot, "    \"{from}\" {arrow} \"{to}\";").unwrap();
+                } else {
+                    writeln!(dot, "    \"{from}\" {arrow} \"{to}\" [label=\"{weight}\"];").unwrap();
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<L: Clone + Eq + Hash, W: Copy + From<u8>> Graph<L, W> {
+    /// Adds an edge of weight 1, returning the ids of its ends.
+    pub fn add_edge(&mut self, from: L, to: L) -> (usize, usize) {
+        self.add_weighted_edge(from, to, W::from(1))
+    }
+}
+
+fn bron_kerbosch(
+    matrix: &[Vec<bool>],
+    clique: &mut Vec<usize>,
+    candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    largest: &mut Vec<usize>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > largest.len() {
+            largest.clone_from(clique);
+        }
+        return;
+    }
+
+    // Neighbors of the pivot are covered by the recursion into the pivot or one of its neighbors.
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .copied()
+        .max_by_key(|&pivot| candidates.iter().filter(|&&id| matrix[pivot][id]).count())
+        .unwrap();
+    let mut remaining = candidates.clone();
+    for id in candidates {
+        if matrix[pivot][id] {
+            continue;
+        }
+        clique.push(id);
+        let neighbors = |ids: &[usize]| {
+            ids.iter()
+                .copied()
+                .filter(|&other| matrix[id][other])
+                .collect()
+        };
+        bron_kerbosch(
+            matrix,
+            clique,
+            neighbors(&remaining),
+            neighbors(&excluded),
+            largest,
+        );
+        clique.pop();
+        remaining.retain(|&other| other != id);
+        excluded.push(id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn directed(edges: &[(&'static str, &'static str, u32)]) -> Graph<&'static str> {
+        let mut graph = Graph::directed();
+        for &(from, to, weight) in edges {
+            graph.add_weighted_edge(from, to, weight);
+        }
+        graph
+    }
+
+    fn labels(graph: &Graph<&'static str>, ids: &[usize]) -> Vec<&'static str> {
+        ids.iter().map(|&id| *graph.label(id)).collect()
+    }
+
+    #[rstest]
+    fn interning() {
+        let mut graph: Graph<&str> = Graph::undirected();
+        assert_eq!(graph.add_edge("a", "b"), (0, 1));
+        assert_eq!(graph.add_edge("b", "c"), (1, 2));
+        assert_eq!(graph.node("a"), 0);
+        assert_eq!(graph.id(&"c"), Some(2));
+        assert_eq!((graph.len(), graph.edge_count()), (3, 2));
+        assert!(graph.has_edge(1, 0));
+
+        assert!(graph.remove_edge_between(1, 0));
+        assert!(!graph.has_edge(0, 1) && !graph.has_edge(1, 0));
+        assert!(!graph.remove_edge_between(0, 1));
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[rstest]
+    fn topological_sort() {
+        let graph = directed(&[("c", "a", 1), ("a", "b", 1), ("c", "b", 1)]);
+        assert_eq!(
+            labels(&graph, &graph.topological_sort().unwrap()),
+            ["c", "a", "b"]
+        );
+
+        let graph = directed(&[("a", "b", 1), ("b", "a", 1)]);
+        assert_eq!(graph.topological_sort(), None);
+    }
+
+    #[rstest]
+    fn longest_path() {
+        let graph = directed(&[("s", "a", 1), ("a", "e", 5), ("s", "b", 2), ("b", "e", 1)]);
+        assert_eq!(graph.longest_path(0, 2), Some(6));
+        assert_eq!(graph.longest_path(2, 0), None);
+
+        let mut graph: Graph<&str> = Graph::undirected();
+        for (from, to) in [("s", "a"), ("a", "b"), ("b", "c"), ("c", "a"), ("c", "e")] {
+            graph.add_edge(from, to);
+        }
+        assert_eq!(graph.longest_path(0, 4), Some(4));
+    }
+
+    #[rstest]
+    fn components() {
+        let graph = directed(&[
+            ("a", "b", 1),
+            ("b", "c", 1),
+            ("c", "a", 1),
+            ("c", "d", 1),
+            ("e", "f", 1),
+        ]);
+        assert_eq!(
+            graph.connected_components(),
+            vec![vec![0, 1, 2, 3], vec![4, 5]]
+        );
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec![3], vec![0, 1, 2], vec![5], vec![4]]
+        );
+    }
+
+    #[rstest]
+    fn min_cut() {
+        // Two triangles joined by a single edge.
+        let mut graph: Graph<u32> = Graph::undirected();
+        for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.add_edge(from, to);
+        }
+        let (cut, side) = graph.min_cut();
+        assert_eq!(cut, 1);
+        assert_eq!(side.len(), 3);
+    }
+
+    #[rstest]
+    fn max_clique() {
+        let mut graph: Graph<&str> = Graph::undirected();
+        for (from, to) in [
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "c"),
+            ("b", "d"),
+            ("c", "d"),
+            ("d", "e"),
+            ("b", "e"),
+            ("c", "e"),
+        ] {
+            graph.add_edge(from, to);
+        }
+        assert_eq!(labels(&graph, &graph.max_clique()), ["b", "c", "d", "e"]);
+    }
+
+    #[rstest]
+    fn dot() {
+        let graph = directed(&[("a", "b", 1), ("b", "c", 7)]);
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"a\";\n    \"b\";\n    \"c\";\n    \"a\" -> \"b\";\n    \"b\" -> \"c\" [label=\"7\"];\n}\n"
+        );
+
+        let mut graph: Graph<&str> = Graph::undirected();
+        graph.add_edge("a", "b");
+        assert!(graph.to_dot().ends_with("    \"a\" -- \"b\";\n}\n"));
+    }
+}
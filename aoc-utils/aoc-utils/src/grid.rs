@@ -0,0 +1,431 @@
+use crate::direction::{Dir4, Dir8};
+use crate::point::Point2;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A rectangular grid stored row by row. Positions are `(x, y)` with `y` growing downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self, String> {
+        if cells.len() != width * height {
+            return Err(format!(
+                "{} cells don't make a {width}x{height} grid",
+                cells.len()
+            ));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses one row per line, mapping every character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, String> {
+        Grid::try_parse(input, |c| Ok::<_, String>(cell(c)))
+    }
+
+    pub fn try_parse<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, String> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).map_err(|err| format!("{err} at ({x}, {y})"))?);
+            }
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(format!(
+                        "row {y} is {line_width} cells wide instead of {width}"
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (isize, isize)) -> bool {
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    fn index_of(&self, (x, y): (usize, usize)) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Like [`Grid::get`], for positions that may have stepped off the grid.
+    pub fn get_signed(&self, (x, y): (isize, isize)) -> Option<&T> {
+        let position = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        self.get(position)
+    }
+
+    /// Treats the grid as tiling the plane infinitely. An empty grid tiles nothing.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> Option<&T> {
+        let x = x.checked_rem_euclid(self.width as isize)?;
+        let y = y.checked_rem_euclid(self.height as isize)?;
+        self.get((x as usize, y as usize))
+    }
+
+    /// Moves `position` by `(dx, dy)`, unless that leaves the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// The orthogonal neighbors inside the grid, clockwise starting upwards.
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(position, dir.offset().into()))
+    }
+
+    /// The orthogonal and diagonal neighbors inside the grid, clockwise starting upwards.
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(position, dir.offset().into()))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells from `start` going right and down until the edge.
+    pub fn diagonal(&self, start: (usize, usize)) -> impl Iterator<Item = &T> {
+        self.ray(start, (1, 1))
+    }
+
+    /// The cells from `start` going left and down until the edge.
+    pub fn anti_diagonal(&self, start: (usize, usize)) -> impl Iterator<Item = &T> {
+        self.ray(start, (-1, 1))
+    }
+
+    /// The cells from `start` in steps of `offset` until the edge.
+    pub fn ray(&self, start: (usize, usize), offset: (isize, isize)) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.index_of(start).map(|_| start), move |&position| {
+            self.step(position, offset)
+        })
+        .map(|position| &self[position])
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn position(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        match self.index_of(position) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{position:?} is outside the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        match self.index_of(position) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{position:?} is outside the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> Index<Point2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point2<usize>) -> &T {
+        &self[(position.x, position.y)]
+    }
+}
+
+impl<T> IndexMut<Point2<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point2<usize>) -> &mut T {
+        &mut self[(position.x, position.y)]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, String> {
+        Grid::parse(input, |c| c)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[rstest]
+    fn parse_and_display() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 1)], 'e');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits = Grid::try_parse("12\n34\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+        assert_eq!(digits.row(1), [3, 4]);
+    }
+
+    #[rstest]
+    #[case("12\n3\n", "row 1 is 1 cells wide instead of 2")]
+    #[case("1x\n", "not a digit at (1, 0)")]
+    fn parse_errors(#[case] input: &str, #[case] expected: &str) {
+        let grid = Grid::try_parse(input, |c| c.to_digit(10).ok_or("not a digit"));
+        assert_eq!(grid, Err(expected.to_string()));
+    }
+
+    #[rstest]
+    fn indexing() {
+        let mut grid = grid();
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_signed((-1, 0)), None);
+        assert_eq!(grid.get_signed((2, 1)), Some(&'f'));
+        assert_eq!(grid.get_wrapping((-1, 2)), Some(&'c'));
+        assert_eq!(Grid::filled(0, 3, '.').get_wrapping((1, 1)), None);
+        assert!(grid.contains((2, 1)) && !grid.contains((2, 2)));
+
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        grid[(1, 0)] = 'y';
+        assert_eq!(grid.row(0), ['x', 'y', 'c']);
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(1, 0), (0, 1)], vec![(1, 0), (1, 1), (0, 1)])]
+    #[case((1, 1), vec![(1, 0), (2, 1), (0, 1)], vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)])]
+    fn neighbors(
+        #[case] position: (usize, usize),
+        #[case] expected4: Vec<(usize, usize)>,
+        #[case] expected8: Vec<(usize, usize)>,
+    ) {
+        let grid = grid();
+        assert_eq!(grid.neighbors4(position).collect::<Vec<_>>(), expected4);
+        assert_eq!(grid.neighbors8(position).collect::<Vec<_>>(), expected8);
+    }
+
+    #[rstest]
+    fn views() {
+        let grid: Grid<char> = "abc\ndef\nghi\n".parse().unwrap();
+        let string = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+        assert_eq!(string(&mut grid.column(1)), "beh");
+        assert_eq!(
+            grid.columns()
+                .map(|mut c| string(&mut c))
+                .collect::<Vec<_>>(),
+            ["adg", "beh", "cfi"]
+        );
+        assert_eq!(string(&mut grid.diagonal((0, 0))), "aei");
+        assert_eq!(string(&mut grid.anti_diagonal((2, 0))), "ceg");
+        assert_eq!(string(&mut grid.ray((2, 2), (-1, 0))), "ihg");
+        assert_eq!(string(&mut grid.ray((3, 0), (1, 0))), "");
+    }
+
+    #[rstest]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+
+    #[rstest]
+    fn searching() {
+        let grid: Grid<char> = "#.#\n.S.\n".parse().unwrap();
+        assert_eq!(grid.position(&'S'), Some((1, 1)));
+        assert_eq!(grid.find(|c| *c == '.'), Some((1, 0)));
+        assert_eq!(
+            grid.positions_of(&'#').collect::<Vec<_>>(),
+            [(0, 0), (2, 0)]
+        );
+        assert_eq!(grid.position(&'E'), None);
+    }
+
+    #[rstest]
+    fn construction() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(grid.cells(), [0, 1, 2, 10, 11, 12]);
+        assert_eq!(Grid::filled(2, 2, 0), Grid::new(2, 2, vec![0; 4]).unwrap());
+        assert!(Grid::new(2, 2, vec![0; 3]).is_err());
+        assert_eq!(Grid::<char>::parse("", |c| c).unwrap().rows().count(), 0);
+    }
+}
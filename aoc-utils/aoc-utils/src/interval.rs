@@ -0,0 +1,378 @@
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as sorted, disjoint and non-adjacent half-open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Default,
+    {
+        self.ranges
+            .iter()
+            .fold(T::default(), |len, range| len + (range.end - range.start))
+    }
+
+    /// Adds `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        if first == last {
+            return;
+        }
+        let mut rest = Vec::new();
+        if self.ranges[first].start < range.start {
+            rest.push(self.ranges[first].start..range.start);
+        }
+        if self.ranges[last - 1].end > range.end {
+            rest.push(range.end..self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, rest);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The values below `value` and the ones at or above it.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for range in &self.ranges {
+            if range.end <= value {
+                below.push(range.clone());
+            } else if range.start >= value {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..value);
+                above.push(value..range.end);
+            }
+        }
+        (IntervalSet { ranges: below }, IntervalSet { ranges: above })
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// A mapping that shifts ranges of values, like the almanac of 2023 day 5, and leaves every
+/// value outside of them as it is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalMap<T> {
+    /// Disjoint source ranges sorted by start, with where their start is mapped to.
+    pieces: Vec<(Range<T>, T)>,
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> IntervalMap<T> {
+    /// The identity mapping.
+    pub fn new() -> Self {
+        IntervalMap { pieces: Vec::new() }
+    }
+
+    /// The source ranges and the ranges they're mapped to.
+    pub fn pieces(&self) -> impl Iterator<Item = (Range<T>, Range<T>)> + '_ {
+        self.pieces.iter().map(|(source, target)| {
+            (
+                source.clone(),
+                *target..*target + (source.end - source.start),
+            )
+        })
+    }
+
+    /// Maps `source` to the range starting at `target`, replacing earlier pieces where they
+    /// overlap.
+    pub fn insert(&mut self, source: Range<T>, target: T) {
+        if source.is_empty() {
+            return;
+        }
+        let first = self
+            .pieces
+            .partition_point(|(other, _)| other.end <= source.start);
+        let last = self
+            .pieces
+            .partition_point(|(other, _)| other.start < source.end);
+        let mut replacement = Vec::new();
+        if first < last {
+            let (before, before_target) = self.pieces[first].clone();
+            if before.start < source.start {
+                replacement.push((before.start..source.start, before_target));
+            }
+            replacement.push((source.clone(), target));
+            let (after, after_target) = self.pieces[last - 1].clone();
+            if after.end > source.end {
+                let shifted = after_target + (source.end - after.start);
+                replacement.push((source.end..after.end, shifted));
+            }
+        } else {
+            replacement.push((source, target));
+        }
+        self.pieces.splice(first..last, replacement);
+    }
+
+    pub fn map(&self, value: T) -> T {
+        let i = self
+            .pieces
+            .partition_point(|(source, _)| source.end <= value);
+        match self.pieces.get(i) {
+            Some((source, target)) if source.start <= value => *target + (value - source.start),
+            _ => value,
+        }
+    }
+
+    /// Splits `range` at the edges of the pieces, pairing every part with its image.
+    pub fn map_range(&self, range: Range<T>) -> Vec<(Range<T>, Range<T>)> {
+        let mut parts = Vec::new();
+        let mut cursor = range.start;
+        let first = self
+            .pieces
+            .partition_point(|(source, _)| source.end <= range.start);
+        for (source, target) in &self.pieces[first..] {
+            if source.start >= range.end {
+                break;
+            }
+            if cursor < source.start {
+                parts.push((cursor..source.start, cursor..source.start));
+            }
+            let start = cursor.max(source.start);
+            let end = range.end.min(source.end);
+            let image = *target + (start - source.start);
+            parts.push((start..end, image..image + (end - start)));
+            cursor = end;
+        }
+        if cursor < range.end {
+            parts.push((cursor..range.end, cursor..range.end));
+        }
+        parts
+    }
+
+    /// The image of every value in `set`.
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.map_range(range.clone()))
+            .map(|(_, image)| image)
+            .collect()
+    }
+
+    /// The mapping that applies `self` and then `next`.
+    pub fn then(&self, next: &IntervalMap<T>) -> IntervalMap<T> {
+        let mut pieces = Vec::new();
+        for (source, target) in self.pieces() {
+            for (part, image) in next.map_range(target.clone()) {
+                let start = source.start + (part.start - target.start);
+                pieces.push((start..start + (part.end - part.start), image.start));
+            }
+        }
+        // Where `self` is the identity, only `next` applies.
+        let domain: IntervalSet<T> = self
+            .pieces
+            .iter()
+            .map(|(source, _)| source.clone())
+            .collect();
+        for (source, target) in &next.pieces {
+            for part in IntervalSet::from(source.clone()).difference(&domain).ranges {
+                let image = *target + (part.start - source.start);
+                pieces.push((part, image));
+            }
+        }
+        pieces.sort_unstable_by_key(|(source, _)| source.start);
+        IntervalMap { pieces }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Default for IntervalMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> FromIterator<(Range<T>, T)>
+    for IntervalMap<T>
+{
+    fn from_iter<I: IntoIterator<Item = (Range<T>, T)>>(pieces: I) -> Self {
+        let mut map = IntervalMap::new();
+        for (source, target) in pieces {
+            map.insert(source, target);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn set(ranges: &[Range<u32>]) -> IntervalSet<u32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[rstest]
+    #[case(&[(1, 3), (5, 7)], &[(1, 3), (5, 7)])]
+    #[case(&[(1, 3), (3, 5)], &[(1, 5)])]
+    #[case(&[(5, 7), (1, 3), (2, 6)], &[(1, 7)])]
+    #[case(&[(1, 3), (4, 4), (8, 9), (0, 2)], &[(0, 3), (8, 9)])]
+    fn insert(#[case] ranges: &[(u32, u32)], #[case] expected: &[(u32, u32)]) {
+        let ranges: Vec<_> = ranges.iter().map(|&(start, end)| start..end).collect();
+        let expected: Vec<_> = expected.iter().map(|&(start, end)| start..end).collect();
+        assert_eq!(set(&ranges).ranges(), expected);
+    }
+
+    #[rstest]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 40..50]);
+        assert_eq!(a.union(&b).ranges(), &[0..30, 40..50]);
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..30]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 40..50]);
+        assert_eq!(a.len(), 20);
+        assert!(a.contains(0) && a.contains(29) && !a.contains(10) && !a.contains(30));
+
+        let (below, above) = a.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above, IntervalSet::from(25..30));
+    }
+
+    /// The seed-to-soil and soil-to-fertilizer maps of 2023 day 5.
+    fn almanac() -> (IntervalMap<u64>, IntervalMap<u64>) {
+        (
+            [(98..100, 50), (50..98, 52)].into_iter().collect(),
+            [(15..52, 0), (52..54, 37), (0..15, 39)]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    #[rstest]
+    fn map() {
+        let (soil, fertilizer) = almanac();
+        assert_eq!(
+            [79, 14, 55, 13, 99].map(|seed| soil.map(seed)),
+            [81, 14, 57, 13, 51]
+        );
+
+        let seeds = IntervalSet::from_iter([79..93, 55..68]);
+        assert_eq!(soil.map_set(&seeds).ranges(), &[57..70, 81..95]);
+
+        let composed = soil.then(&fertilizer);
+        for seed in 0..110 {
+            assert_eq!(composed.map(seed), fertilizer.map(soil.map(seed)), "{seed}");
+        }
+        assert_eq!(
+            composed.map_set(&seeds),
+            fertilizer.map_set(&soil.map_set(&seeds))
+        );
+    }
+
+    #[rstest]
+    fn overlapping_insert() {
+        let mut map: IntervalMap<u32> = [(0..10, 100)].into_iter().collect();
+        map.insert(3..5, 50);
+        assert_eq!(
+            map.pieces().collect::<Vec<_>>(),
+            vec![(0..3, 100..103), (3..5, 50..52), (5..10, 105..110)]
+        );
+        assert_eq!(
+            map.map_range(8..12),
+            vec![(8..10, 108..110), (10..12, 10..12)]
+        );
+    }
+}
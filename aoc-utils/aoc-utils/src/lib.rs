@@ -1,113 +1,110 @@
-use reqwest::blocking::Client;
-use rookie::firefox;
-use std::fs;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod cycle;
+pub mod direction;
+mod error;
+pub mod fetch;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod page;
+pub mod parse;
+pub mod point;
+pub mod profile;
+pub mod runner;
+pub mod search;
+pub mod session;
+pub mod solution;
+pub mod submit;
+pub mod throttle;
+
+pub use direction::{Dir4, Dir8};
+pub use error::AocError;
+pub use fetch::Fetcher;
+pub use grid::Grid;
+pub use page::PuzzlePage;
+pub use parse::ParseError;
+pub use point::{Point2, Point3};
+pub use profile::Profile;
+pub use solution::Solution;
+pub use submit::{submit_answer, Part, SubmitOutcome};
+
+use profile::selected_profile;
+use std::env;
 use std::path::Path;
 
-const CACHE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/cache");
+const OFFLINE_ENV_VAR: &str = "AOC_OFFLINE";
 
-fn get_session() -> Result<String, &'static str> {
-    let cookies = firefox(Some(vec!["adventofcode.com".to_string()]))
-        .map_err(|_| "Failed to get session cookie.")?;
-    cookies
-        .first()
-        .map(|cookie| cookie.value.clone())
-        .ok_or("Failed to get session cookie.")
+/// Whether `AOC_OFFLINE` is set to anything but `0` or `false`.
+pub fn is_offline() -> bool {
+    env::var(OFFLINE_ENV_VAR).is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
 }
 
-fn fetch_text(url: &str) -> Result<String, String> {
-    let session = get_session()?;
-    let client = Client::new();
-    let response = client
-        .get(url)
-        .header("Cookie", format!("session={session}"))
-        .send()
-        .map_err(|_| "Failed to send request.")?;
-
-    if response.status() != 200 {
-        return Err(format!("Received non-200 response: {}", response.status()));
-    }
-
-    response
-        .text()
-        .map_err(|_| "Failed to read response text.".into())
+/// Whether `AOC_OFFLINE=examples` allows falling back to the example inputs.
+pub fn offline_examples() -> bool {
+    env::var(OFFLINE_ENV_VAR).is_ok_and(|value| value == "examples")
 }
 
-fn fetch_problem(year: u32, day: u32) -> Result<String, String> {
-    let url = format!("https://adventofcode.com/{year}/day/{day}");
-    fetch_text(&url)
+pub fn get_answers(year: u32, day: u32) -> Result<(Option<String>, Option<String>), AocError> {
+    get_answers_for(&selected_profile(), year, day)
 }
 
-fn fetch_input(year: u32, day: u32) -> Result<String, String> {
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    fetch_text(&url)
+pub fn get_answers_for(
+    profile: &str,
+    year: u32,
+    day: u32,
+) -> Result<(Option<String>, Option<String>), AocError> {
+    let profile = Profile::get(profile)?;
+    let cache = profile.cache();
+    let answers = if is_offline() {
+        cache
+            .cached_answers(year, day)?
+            .ok_or_else(|| AocError::Offline {
+                year,
+                day,
+                kind: "answers",
+                tried: vec![cache.answers_path(year, day)],
+            })?
+    } else {
+        cache.answers(profile.fetcher(), year, day)?
+    };
+
+    Ok((
+        answers.answer(Part::One).map(str::to_string),
+        answers.answer(Part::Two).map(str::to_string),
+    ))
 }
 
-pub fn get_answers(year: u32, day: u32) -> Result<(Option<String>, Option<String>), String> {
-    let cache_file = format!("{CACHE_DIR}/{year}-{day}-answers.txt");
-
-    let mut answers = (None, None);
-
-    if let Ok(contents) = fs::read_to_string(&cache_file) {
-        let mut lines = contents.lines();
-        if let Some(answer) = lines.next() {
-            if !answer.is_empty() {
-                answers.0 = Some(answer.to_string());
-            }
-        }
-        if let Some(answer) = lines.next() {
-            if !answer.is_empty() {
-                answers.1 = Some(answer.to_string());
-            }
-        }
-        return Ok(answers);
-    }
-
-    let contents = fetch_problem(year, day)?;
-    let mut contents: &str = &contents;
-
-    if let Some(index) = contents.find("Your puzzle answer was <code>") {
-        contents = &contents[index + "Your puzzle answer was <code>".len()..];
-        let end = contents.find("</code>.").unwrap();
-        let answer = contents[..end].to_string();
-        answers.0 = Some(answer);
-    }
-
-    if let Some(index) = contents.find("Your puzzle answer was <code>") {
-        contents = &contents[index + "Your puzzle answer was <code>".len()..];
-        let end = contents.find("</code>.").unwrap();
-        let answer = contents[..end].to_string();
-        answers.1 = Some(answer);
-    }
-
-    if contents.contains("Both parts of this puzzle are complete! They provide two gold stars: **")
-    {
-        let mut contents = String::new();
-        if let Some(answer) = &answers.0 {
-            contents.push_str(answer);
-            contents.push('\n');
-        }
-        if let Some(answer) = &answers.1 {
-            contents.push_str(answer);
-            contents.push('\n');
-        }
-        fs::create_dir_all(CACHE_DIR).map_err(|_| "Failed to create cache directory.")?;
-        fs::write(&cache_file, &contents).map_err(|_| "Failed to write cache file.")?;
-    }
-
-    Ok(answers)
+pub fn get_input_file(year: u32, day: u32) -> Result<String, AocError> {
+    get_input_file_for(&selected_profile(), year, day)
 }
 
-pub fn get_input_file(year: u32, day: u32) -> Result<String, String> {
-    let cache_file = format!("{CACHE_DIR}/{year}-{day}-input.txt");
+pub fn get_input_file_for(profile: &str, year: u32, day: u32) -> Result<String, AocError> {
+    let profile = Profile::get(profile)?;
+    let cache_file = profile.cache().input_file(profile.fetcher(), year, day)?;
 
-    if Path::new(&cache_file).exists() {
-        return Ok(cache_file);
-    }
-
-    let contents = fetch_input(year, day)?;
+    Ok(cache_file.to_string_lossy().into_owned())
+}
 
-    fs::create_dir_all(CACHE_DIR).map_err(|_| "Failed to create cache directory.")?;
-    fs::write(&cache_file, &contents).map_err(|_| "Failed to write cache file.")?;
+/// Like [`get_input_file_for`], but never downloads anything. See [`cache::Cache::offline_input_file`].
+pub fn get_offline_input_file_for(
+    profile: &str,
+    year: u32,
+    day: u32,
+    src_dir: &Path,
+) -> Result<String, AocError> {
+    let cache_file = Profile::get(profile)?.cache().offline_input_file(
+        year,
+        day,
+        src_dir,
+        offline_examples(),
+    )?;
+
+    Ok(cache_file.to_string_lossy().into_owned())
+}
 
-    Ok(cache_file)
+pub fn invalidate(year: u32, day: u32) -> Result<(), AocError> {
+    Profile::selected()?.cache().invalidate(year, day)
 }
@@ -0,0 +1,96 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// The least common multiple of all values, e.g. when several periods line up again.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    values.into_iter().fold(1, lcm)
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every pair with the Chinese remainder theorem, which
+/// also works for moduli that share factors.
+///
+/// Returns the smallest non-negative `x` and the combined modulus, or `None` if the congruences
+/// contradict each other.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut x, mut modulus): (i128, i128) = (0, 1);
+    for (residue, other) in congruences {
+        let (residue, other) = (residue as i128, other as i128);
+        let (g, p, _) = extended_gcd(modulus, other);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let combined = modulus / g * other;
+        x = (x + modulus * (difference / g * p % (other / g))).rem_euclid(combined);
+        modulus = combined;
+    }
+    Some((x as i64, modulus as i64))
+}
+
+/// Combines events that happen at `offset + k * period` for every `k >= 0` into the first time
+/// they all happen together and how often that repeats.
+pub fn combine_periods(periods: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let periods: Vec<(i64, i64)> = periods.into_iter().collect();
+    let (x, modulus) = crt(periods.iter().copied())?;
+    // `x` may come before some of the offsets, in which case a later repetition is the first.
+    let latest = periods.iter().map(|&(offset, _)| offset).max().unwrap_or(0);
+    let behind = (latest - x).max(0);
+    let first = x + (behind + modulus - 1) / modulus * modulus;
+    Some((first, modulus))
+}
+
+/// Returns `(g, p, q)` with `g = gcd(a, b) = a * p + b * q`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, p, q) = extended_gcd(b, a % b);
+        (g, q, p - a / b * q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn lcm_test() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm_all([3, 4, 6]), 12);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[rstest]
+    #[case(&[(2, 3), (3, 5), (2, 7)], Some((23, 105)))]
+    #[case(&[(1, 4), (3, 6)], Some((9, 12)))]
+    #[case(&[(1, 4), (2, 6)], None)]
+    #[case(&[(-1, 5)], Some((4, 5)))]
+    #[case(&[], Some((0, 1)))]
+    fn crt_test(#[case] congruences: &[(i64, i64)], #[case] expected: Option<(i64, i64)>) {
+        assert_eq!(crt(congruences.iter().copied()), expected);
+    }
+
+    #[rstest]
+    #[case(&[(3, 4), (5, 6)], Some((11, 12)))]
+    #[case(&[(13, 12), (1, 6)], Some((13, 12)))]
+    #[case(&[(100, 3), (1, 3)], Some((100, 3)))]
+    #[case(&[(0, 2), (1, 2)], None)]
+    fn combine_periods_test(#[case] periods: &[(i64, i64)], #[case] expected: Option<(i64, i64)>) {
+        assert_eq!(combine_periods(periods.iter().copied()), expected);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::Part;
+use scraper::{ElementRef, Html, Selector};
+
+const ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePart {
+    pub text: String,
+    /// Contents of the `<pre><code>` blocks, usually the example inputs.
+    pub code_blocks: Vec<String>,
+    /// Contents of the `<code><em>` spans, usually the example answers.
+    pub highlighted: Vec<String>,
+}
+
+/// An example input proposed from the puzzle text, with the answers it should give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzlePage {
+    pub title: String,
+    pub parts: Vec<PuzzlePart>,
+    pub answers: Vec<String>,
+    pub stars: u8,
+}
+
+impl PuzzlePage {
+    pub fn parse(html: &str) -> Result<Self, String> {
+        let document = Html::parse_document(html);
+
+        let articles: Vec<ElementRef> = document.select(&selector("article.day-desc")).collect();
+        let first = articles.first().ok_or("no puzzle description found")?;
+
+        let heading = first
+            .select(&selector("h2"))
+            .next()
+            .map(|h2| h2.text().collect::<String>())
+            .ok_or("puzzle description has no heading")?;
+        let heading = heading.trim().trim_matches('-').trim();
+        let title = heading
+            .split_once(": ")
+            .map_or(heading, |(_, title)| title)
+            .to_string();
+
+        let parts = articles
+            .iter()
+            .map(|article| parse_part(*article))
+            .collect();
+
+        let answers = document
+            .select(&selector("main > p"))
+            .filter(|p| p.text().collect::<String>().starts_with(ANSWER_PREFIX))
+            .map(|p| {
+                p.select(&selector("code"))
+                    .next()
+                    .map(|code| code.text().collect::<String>())
+                    .ok_or("answer is missing its <code> element")
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let success = document
+            .select(&selector("p.day-success"))
+            .map(|p| p.text().collect::<String>())
+            .next()
+            .unwrap_or_default();
+        let stars = if success.starts_with("Both parts of this puzzle are complete") {
+            2
+        } else if success.starts_with("The first half of this puzzle is complete") {
+            1
+        } else {
+            0
+        };
+
+        if answers.len() > stars as usize {
+            return Err(format!(
+                "found {} answers, but only {stars} stars",
+                answers.len()
+            ));
+        }
+
+        Ok(PuzzlePage {
+            title,
+            parts,
+            answers,
+            stars,
+        })
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        self.answers.get(part.index()).map(String::as_str)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.stars == 2
+    }
+
+    /// The first code block of a part is usually its example input and the last highlighted
+    /// value the answer for it. Part two reuses the first example unless it brings its own.
+    pub fn examples(&self) -> Vec<Example> {
+        let Some(first) = self.parts.first() else {
+            return Vec::new();
+        };
+        let mut examples = Vec::new();
+        if let Some(input) = first.code_blocks.first() {
+            examples.push(Example {
+                input: input.clone(),
+                part1: first.highlighted.last().cloned(),
+                part2: None,
+            });
+        }
+
+        let Some(second) = self.parts.get(1) else {
+            return examples;
+        };
+        let answer = second.highlighted.last().cloned();
+        match second.code_blocks.first() {
+            Some(input) if examples.iter().all(|example| example.input != *input) => {
+                examples.push(Example {
+                    input: input.clone(),
+                    part1: None,
+                    part2: answer,
+                });
+            }
+            _ => {
+                if let Some(example) = examples.first_mut() {
+                    example.part2 = answer;
+                }
+            }
+        }
+        examples
+    }
+}
+
+fn selector(selector: &str) -> Selector {
+    Selector::parse(selector).expect("Selectors are valid.")
+}
+
+fn parse_part(article: ElementRef) -> PuzzlePart {
+    let text = article.text().collect::<String>().trim().to_string();
+    let code_blocks = article
+        .select(&selector("pre > code"))
+        .map(|code| code.text().collect())
+        .collect();
+    let highlighted = article
+        .select(&selector("code > em"))
+        .map(|em| em.text().collect())
+        .collect();
+
+    PuzzlePart {
+        text,
+        code_blocks,
+        highlighted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(include_str!("fixtures/2024-1-zero-stars.html"), 0, 1, &[])]
+    #[case(include_str!("fixtures/2024-1-one-star.html"), 1, 2, &["2378066"])]
+    #[case(include_str!("fixtures/2024-1-two-stars.html"), 2, 2, &["2378066", "18934359"])]
+    #[case(include_str!("fixtures/2024-25-two-stars.html"), 2, 2, &["3162"])]
+    fn parse_stars_and_answers(
+        #[case] html: &str,
+        #[case] stars: u8,
+        #[case] parts: usize,
+        #[case] answers: &[&str],
+    ) {
+        let page = PuzzlePage::parse(html).unwrap();
+        assert_eq!(page.stars, stars);
+        assert_eq!(page.parts.len(), parts);
+        assert_eq!(page.answers, answers);
+    }
+
+    #[rstest]
+    fn parse_title_and_examples() {
+        let page = PuzzlePage::parse(include_str!("fixtures/2024-1-two-stars.html")).unwrap();
+        assert_eq!(page.title, "Historian Hysteria");
+        assert_eq!(
+            page.parts[0].code_blocks,
+            ["3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"]
+        );
+        assert_eq!(page.parts[0].highlighted, ["11"]);
+        assert!(page.parts[1].code_blocks.is_empty());
+        assert_eq!(page.parts[1].highlighted, ["9", "31"]);
+        assert!(page.parts[1].text.starts_with("--- Part Two ---"));
+    }
+
+    #[rstest]
+    fn parse_day_25() {
+        let page = PuzzlePage::parse(include_str!("fixtures/2024-25-two-stars.html")).unwrap();
+        assert_eq!(page.title, "Code Chronicle");
+        assert!(page.is_complete());
+        assert_eq!(page.answer(Part::One), Some("3162"));
+        assert_eq!(page.answer(Part::Two), None);
+        assert_eq!(page.parts[0].highlighted, ["3"]);
+    }
+
+    #[rstest]
+    fn examples_reuse_first_input() {
+        let page = PuzzlePage::parse(include_str!("fixtures/2024-1-two-stars.html")).unwrap();
+        assert_eq!(
+            page.examples(),
+            [Example {
+                input: "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n".to_string(),
+                part1: Some("11".to_string()),
+                part2: Some("31".to_string()),
+            }]
+        );
+    }
+
+    #[rstest]
+    fn examples_with_new_input_for_part2() {
+        let part = |code: &str, answer: &str| PuzzlePart {
+            text: String::new(),
+            code_blocks: vec![code.to_string()],
+            highlighted: vec!["1".to_string(), answer.to_string()],
+        };
+        let page = PuzzlePage {
+            title: String::new(),
+            parts: vec![part("1abc2\n", "142"), part("two1nine\n", "281")],
+            answers: Vec::new(),
+            stars: 1,
+        };
+        let examples = page.examples();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].part1.as_deref(), Some("142"));
+        assert_eq!(examples[0].part2, None);
+        assert_eq!(examples[1].input, "two1nine\n");
+        assert_eq!(examples[1].part1, None);
+        assert_eq!(examples[1].part2.as_deref(), Some("281"));
+    }
+
+    #[rstest]
+    #[case("<html><body><main><p>Please log in.</p></main></body></html>")]
+    #[case("")]
+    fn parse_without_puzzle(#[case] html: &str) {
+        assert!(PuzzlePage::parse(html).is_err());
+    }
+}
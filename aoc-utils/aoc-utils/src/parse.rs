@@ -0,0 +1,425 @@
+use crate::graph::Graph;
+use crate::grid::Grid;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use winnow::ascii::{digit1, line_ending, multispace0, space0, space1};
+use winnow::combinator::{
+    alt, cut_err, eof, not, opt, repeat, separated, separated_pair, terminated,
+};
+use winnow::error::{ContextError, StrContext, StrContextValue};
+use winnow::token::{any, one_of, take_till, take_while};
+use winnow::{ModalResult, Parser};
+
+fn expected(description: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(description))
+}
+
+/// A decimal integer of any type, with an optional sign.
+pub fn number<T: FromStr>(input: &mut &str) -> ModalResult<T> {
+    (opt(one_of(['+', '-'])), digit1)
+        .take()
+        .parse_to()
+        .context(StrContext::Label("number"))
+        .context(expected("an integer"))
+        .parse_next(input)
+}
+
+/// Numbers on one line separated by spaces, commas or both, like `7 6 4 2` or `75,47,61`.
+pub fn numbers<T: FromStr>(input: &mut &str) -> ModalResult<Vec<T>> {
+    // Spaces at the end of the line don't separate anything, they're just skipped.
+    let separator = alt((
+        (space0, ',', space0).void(),
+        (space1, not(alt((line_ending, eof)))).void(),
+    ));
+    terminated(separated_strict(number, separator), space0).parse_next(input)
+}
+
+/// Every integer in `text`, skipping whatever surrounds them, e.g. `[-3, 5]` in
+/// `p=-3 v=+5`.
+pub fn extract_numbers<T: FromStr>(text: &str) -> Vec<T> {
+    let mut text = text;
+    let number_or_skip = alt((number.map(Some), any.map(|_| None)));
+    let numbers: ModalResult<Vec<Option<T>>> = repeat(0.., number_or_skip).parse_next(&mut text);
+    // Skipping any character can't fail.
+    numbers.unwrap().into_iter().flatten().collect()
+}
+
+/// One or more lines, each parsed with `line`. They end at a blank line or the end of the input,
+/// every line before has to match.
+pub fn lines<'s, O, P>(line: P) -> impl Parser<&'s str, Vec<O>, ContextError>
+where
+    P: Parser<&'s str, O, ContextError>,
+{
+    let end = alt((line_ending.void(), (multispace0, eof).void()));
+    separated_strict(line, (line_ending, not(end)))
+}
+
+/// One or more blocks separated by blank lines, each parsed with `block`.
+pub fn blocks<'s, O, P>(block: P) -> impl Parser<&'s str, Vec<O>, ContextError>
+where
+    P: Parser<&'s str, O, ContextError>,
+{
+    separated_strict(block, (line_ending, line_ending, not((multispace0, eof))))
+}
+
+/// Like `separated(1.., item, separator)`, but an item has to follow every separator, so the
+/// error points at the bad item instead of the separator before it.
+fn separated_strict<'s, O, S>(
+    mut item: impl Parser<&'s str, O, ContextError>,
+    mut separator: impl Parser<&'s str, S, ContextError>,
+) -> impl Parser<&'s str, Vec<O>, ContextError> {
+    move |input: &mut &'s str| {
+        let mut items = vec![item.parse_next(input)?];
+        while opt(separator.by_ref()).parse_next(input)?.is_some() {
+            items.push(cut_err(item.by_ref()).parse_next(input)?);
+        }
+        Ok(items)
+    }
+}
+
+/// A `key: value` pair.
+pub fn key_value<'s, K, V, PK, PV>(key: PK, value: PV) -> impl Parser<&'s str, (K, V), ContextError>
+where
+    PK: Parser<&'s str, K, ContextError>,
+    PV: Parser<&'s str, V, ContextError>,
+{
+    separated_pair(key, (space0, ':', space0), value).context(StrContext::Label("key: value"))
+}
+
+/// A section of `key: value` lines.
+pub fn key_values<'s, K, V, PK, PV>(
+    key: PK,
+    value: PV,
+) -> impl Parser<&'s str, Vec<(K, V)>, ContextError>
+where
+    PK: Parser<&'s str, K, ContextError>,
+    PV: Parser<&'s str, V, ContextError>,
+{
+    lines(key_value(key, value))
+}
+
+/// A word of letters, digits and underscores, like the node names of most inputs.
+pub fn name<'s>(input: &mut &'s str) -> ModalResult<&'s str> {
+    take_while(1.., |c: char| c.is_alphanumeric() || c == '_')
+        .context(StrContext::Label("name"))
+        .context(expected("a name"))
+        .parse_next(input)
+}
+
+/// A `name -> a, b, c` line.
+pub fn graph_line<'s>(input: &mut &'s str) -> ModalResult<(&'s str, Vec<&'s str>)> {
+    separated_pair(
+        name,
+        (space0, "->", space0),
+        separated(1.., name, (',', space0)),
+    )
+    .context(StrContext::Label("graph line"))
+    .parse_next(input)
+}
+
+/// `name -> a, b, c` lines as a directed graph.
+pub fn graph<'s>(input: &mut &'s str) -> ModalResult<Graph<&'s str>> {
+    lines(graph_line)
+        .map(|lines| {
+            let mut graph = Graph::directed();
+            for (from, targets) in lines {
+                graph.node(from);
+                for to in targets {
+                    graph.add_edge(from, to);
+                }
+            }
+            graph
+        })
+        .parse_next(input)
+}
+
+#[derive(Debug)]
+struct GridError(String);
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for GridError {}
+
+/// Consecutive non-empty lines as a grid, mapping every character with `cell`.
+pub fn grid<'s, T, E: Display>(
+    mut cell: impl FnMut(char) -> Result<T, E>,
+) -> impl Parser<&'s str, Grid<T>, ContextError> {
+    lines(take_till(1.., ['\r', '\n']))
+        .take()
+        .try_map(move |rows| Grid::try_parse(rows, &mut cell).map_err(GridError))
+        .context(StrContext::Label("grid"))
+}
+
+/// Consecutive non-empty lines as a grid of characters.
+pub fn char_grid(input: &mut &str) -> ModalResult<Grid<char>> {
+    grid(Ok::<_, GridError>).parse_next(input)
+}
+
+/// Why and where an input couldn't be parsed. It's displayed like
+///
+/// ```text
+/// line 2, column 3: invalid number, expected an integer, found 'x'
+///   |
+/// 2 | 1 x
+///   |   ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starts at 1.
+    pub line: usize,
+    /// Starts at 1 and counts characters.
+    pub column: usize,
+    /// The offending line, without its line ending.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at byte `offset` of `input`.
+    pub fn new(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let (before, after) = input.split_at(offset);
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        let end = after.find(['\r', '\n']).map_or(input.len(), |i| offset + i);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[start..].chars().count() + 1,
+            text: input[start..end].to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error at the start of `part`, which has to be a slice of `input`, like a line or a
+    /// regex match.
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        assert!(offset <= input.len(), "{part:?} isn't part of the input");
+        ParseError::new(input, offset, message)
+    }
+
+    fn from_context(input: &str, offset: usize, error: &ContextError) -> Self {
+        let found = match input[offset..].chars().next() {
+            Some(c) => format!("{c:?}"),
+            None => "end of input".to_string(),
+        };
+        let mut labels = Vec::new();
+        let mut expected = Vec::new();
+        for context in error.context() {
+            match context {
+                StrContext::Label(label) => labels.push(label.to_string()),
+                StrContext::Expected(value) => expected.push(value.to_string()),
+                _ => {}
+            }
+        }
+
+        // The innermost label is the most specific one.
+        let mut parts = Vec::new();
+        if let Some(label) = labels.first() {
+            parts.push(format!("invalid {label}"));
+        }
+        if !expected.is_empty() {
+            parts.push(format!("expected {}, found {found}", expected.join(" or ")));
+        }
+        if let Some(cause) = error.cause() {
+            parts.push(cause.to_string());
+        }
+        if parts.is_empty() {
+            parts.push(format!("unexpected {found}"));
+        }
+        ParseError::new(input, offset, parts.join(", "))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        // Tabs are kept so the caret lines up however wide they're shown.
+        let indent: String = self
+            .text
+            .chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{gutter} | {indent}^")
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `part` of `input` with [`FromStr`], like a number a regex matched.
+pub fn parse_part<T>(input: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    part.parse()
+        .map_err(|err| ParseError::at(input, part, format!("couldn't parse {part:?}: {err}")))
+}
+
+/// Parses all of `input` with `parser`, except for trailing whitespace.
+pub fn parse_all<'s, O>(
+    parser: impl Parser<&'s str, O, ContextError>,
+    input: &'s str,
+) -> Result<O, ParseError> {
+    terminated(parser, multispace0)
+        .parse(input)
+        .map_err(|err| ParseError::from_context(input, err.offset(), err.inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("7 6 4", vec![7, 6, 4])]
+    #[case("75,47,61", vec![75, 47, 61])]
+    #[case("1, -2 ,+3", vec![1, -2, 3])]
+    #[case("5 6  ", vec![5, 6])]
+    #[case("8\t9", vec![8, 9])]
+    fn numbers_test(#[case] input: &str, #[case] expected: Vec<i32>) {
+        assert_eq!(numbers.parse(input), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("p=0,4 v=3,-3", vec![0, 4, 3, -3])]
+    #[case("Button A: X+94, Y+34", vec![94, 34])]
+    #[case("Register A: 729\n\nProgram: 0,1,5", vec![729, 0, 1, 5])]
+    #[case("no numbers - here", vec![])]
+    fn extract(#[case] text: &str, #[case] expected: Vec<i64>) {
+        assert_eq!(extract_numbers::<i64>(text), expected);
+    }
+
+    #[rstest]
+    fn lines_and_blocks() {
+        let input = "47|53\n97|13\n\n75,47,61\n97,61\n";
+        let (rules, updates): (Vec<(u32, u32)>, Vec<Vec<u32>>) = terminated(
+            separated_pair(
+                lines(separated_pair(number, '|', number)),
+                (line_ending, line_ending),
+                lines(numbers),
+            ),
+            multispace0,
+        )
+        .parse(input)
+        .unwrap();
+        assert_eq!(rules, [(47, 53), (97, 13)]);
+        assert_eq!(updates, [vec![75, 47, 61], vec![97, 61]]);
+
+        let groups: Vec<Vec<u32>> = blocks(lines(number)).parse("1\n2\n\n3\n\n4\n5").unwrap();
+        assert_eq!(groups, [vec![1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[rstest]
+    fn key_values_test() {
+        let wires: Vec<(&str, u8)> = key_values(name, number).parse("x00: 1\ny01: 0").unwrap();
+        assert_eq!(wires, [("x00", 1), ("y01", 0)]);
+
+        let equation: (u64, Vec<u64>) = key_value(number, numbers).parse("190: 10 19").unwrap();
+        assert_eq!(equation, (190, vec![10, 19]));
+    }
+
+    #[rstest]
+    fn grids() {
+        let (map, moves) = separated_pair(char_grid, (line_ending, line_ending), name)
+            .parse("#.#\n.@.\n\nvv")
+            .unwrap();
+        assert_eq!(map.to_string(), "#.#\n.@.\n");
+        assert_eq!(moves, "vv");
+
+        let digits = grid(|c: char| c.to_digit(10).ok_or("not a digit"))
+            .parse("123\n456")
+            .unwrap();
+        assert_eq!(digits[(2, 1)], 6);
+    }
+
+    #[rstest]
+    fn graphs() {
+        let graph = graph.parse("a -> b, c\nb -> c\nc -> d").unwrap();
+        assert_eq!(graph.labels(), ["a", "b", "c", "d"]);
+        assert_eq!(graph.edge_count(), 4);
+        assert!(graph.has_edge(0, 2));
+    }
+
+    #[rstest]
+    #[case(numbers::<u8>, "1 x", "expected an integer")]
+    #[case(numbers::<u8>, "1 300", "invalid number")]
+    fn errors(
+        #[case] mut parser: fn(&mut &str) -> ModalResult<Vec<u8>>,
+        #[case] input: &str,
+        #[case] message: &str,
+    ) {
+        let err = parser.parse(input).unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+
+    #[rstest]
+    fn grid_errors() {
+        let err = grid(|c: char| c.to_digit(10).ok_or("not a digit"))
+            .parse("12\n3x")
+            .unwrap_err();
+        assert!(err.to_string().contains("not a digit at (1, 1)"), "{err}");
+
+        let err = char_grid.parse("12\n3").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("row 1 is 1 cells wide instead of 2"),
+            "{err}"
+        );
+    }
+
+    #[rstest]
+    #[case("1 2\n3 x\n", (2, 3, "3 x"), "invalid number, expected an integer, found 'x'")]
+    #[case("1 2\n3 4\n5,", (3, 3, "5,"), "invalid number, expected an integer, found end of input")]
+    #[case("1 2\r\n\r\nx", (3, 1, "x"), "unexpected 'x'")]
+    #[case("1 2\n3 4 5000", (2, 5, "3 4 5000"), "invalid number, expected an integer, found '5'")]
+    fn parse_all_errors(
+        #[case] input: &str,
+        #[case] position: (usize, usize, &str),
+        #[case] message: &str,
+    ) {
+        let err = parse_all(lines(numbers::<u8>), input).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), position);
+        assert_eq!(err.message, message);
+    }
+
+    #[rstest]
+    fn parse_error_display() {
+        let input = "Game 1: 3 red\n\tGame 2 4 blue\n";
+        assert_eq!(
+            parse_all(lines(numbers::<u8>), "1 2\n\n"),
+            Ok(vec![vec![1, 2]])
+        );
+
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(parse_part::<u8>(input, &line[6..7]), Ok(2));
+        let err = parse_part::<u8>(input, &line[1..5]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(
+            err.message,
+            "couldn't parse \"Game\": invalid digit found in string"
+        );
+
+        let err = ParseError::at(input, &line[7..], "expected ':'");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 8: expected ':'\n  |\n2 | \tGame 2 4 blue\n  | \t      ^"
+        );
+    }
+}
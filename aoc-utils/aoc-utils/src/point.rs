@@ -0,0 +1,230 @@
+use crate::direction::{Dir4, Dir8};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+fn abs_diff<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Implements the arithmetic shared by `Point2` and `Point3` component-wise.
+macro_rules! impl_point {
+    ($point:ident { $($c:ident),+ }) => {
+        impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> $point<T> {
+            /// The distance when moving along the axes, which also works for unsigned
+            /// coordinates.
+            pub fn manhattan(self, other: Self) -> T {
+                let mut distances = [$(abs_diff(self.$c, other.$c)),+].into_iter();
+                let first = distances.next().unwrap();
+                distances.fold(first, |sum, distance| sum + distance)
+            }
+
+            /// The distance when diagonal moves are allowed too.
+            pub fn chebyshev(self, other: Self) -> T {
+                let mut distances = [$(abs_diff(self.$c, other.$c)),+].into_iter();
+                let first = distances.next().unwrap();
+                distances.fold(first, |max, distance| if distance > max { distance } else { max })
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($c: self.$c * factor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$c += other.$c;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$c -= other.$c;)+
+            }
+        }
+
+        impl<T: Display> Display for $point<T> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let coordinates = [$(self.$c.to_string()),+];
+                write!(f, "({})", coordinates.join(", "))
+            }
+        }
+    };
+}
+
+impl_point!(Point2 { x, y });
+impl_point!(Point3 { x, y, z });
+
+impl Point2<usize> {
+    /// Moves one step, unless that would make a coordinate negative.
+    pub fn checked_step(self, offset: impl Into<Point2<isize>>) -> Option<Self> {
+        let offset = offset.into();
+        Some(Point2::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+impl<T: From<i8> + Add<Output = T>> Add<Dir4> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, dir: Dir4) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl<T: From<i8> + Add<Output = T>> Add<Dir8> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, dir: Dir8) -> Self {
+        self + dir.offset()
+    }
+}
+
+impl<T: From<i8> + AddAssign> AddAssign<Dir4> for Point2<T> {
+    fn add_assign(&mut self, dir: Dir4) {
+        *self += dir.offset();
+    }
+}
+
+impl<T: From<i8> + AddAssign> AddAssign<Dir8> for Point2<T> {
+    fn add_assign(&mut self, dir: Dir8) {
+        *self += dir.offset();
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn arithmetic() {
+        let mut a = Point2::new(1, -2);
+        let b = Point2::new(3, 4);
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(-2, -6));
+        assert_eq!(b * 3, Point2::new(9, 12));
+        assert_eq!(-a, Point2::new(-1, 2));
+        a += b;
+        a -= Point2::new(1, 1);
+        assert_eq!(a, Point2::new(3, 1));
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 2,
+            Point3::new(3, 4, 5)
+        );
+    }
+
+    #[rstest]
+    fn distances() {
+        assert_eq!(Point2::new(1, -2).manhattan(Point2::new(-3, 4)), 10);
+        assert_eq!(Point2::new(1, -2).chebyshev(Point2::new(-3, 4)), 6);
+        assert_eq!(Point2::new(5_usize, 1).manhattan(Point2::new(2, 3)), 5);
+        assert_eq!(Point3::new(0, 0, 0).manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::new(0, 0, 0).chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[rstest]
+    fn directions() {
+        let mut point = Point2::new(0_i64, 0);
+        point += Dir4::Up;
+        point = point + Dir8::SouthEast + Dir4::Right;
+        assert_eq!(point, Point2::new(2, 0));
+
+        let corner = Point2::new(0_usize, 0);
+        assert_eq!(corner.checked_step(Dir4::Left.offset()), None);
+        assert_eq!(
+            corner.checked_step(Dir8::SouthEast),
+            Some(Point2::new(1, 1))
+        );
+    }
+
+    #[rstest]
+    fn conversions() {
+        assert_eq!(Point2::from((1, 2)), Point2::new(1, 2));
+        assert_eq!(<(i32, i32)>::from(Point2::new(1, 2)), (1, 2));
+        assert_eq!(Point3::from((1, 2, 3)).to_string(), "(1, 2, 3)");
+    }
+}
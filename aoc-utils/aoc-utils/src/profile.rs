@@ -0,0 +1,176 @@
+use crate::cache::Cache;
+use crate::config::{Config, ProfileConfig};
+use crate::session::{BrowserSession, EnvSession, FileSession, SessionChain};
+use crate::throttle::{self, Throttle};
+use crate::{AocError, Fetcher};
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Mutex, OnceLock};
+
+pub const DEFAULT_PROFILE: &str = "default";
+const PROFILE_ENV_VAR: &str = "AOC_PROFILE";
+
+/// The profile named by `AOC_PROFILE`, or the default one.
+pub fn selected_profile() -> String {
+    env::var(PROFILE_ENV_VAR).unwrap_or_else(|_| DEFAULT_PROFILE.to_string())
+}
+
+pub struct Profile {
+    name: String,
+    fetcher: Fetcher,
+    cache: Cache,
+}
+
+impl Profile {
+    pub fn from_config(name: &str, config: &Config) -> Result<Profile, AocError> {
+        // The name ends up in the paths of the cache and the session file.
+        if !is_valid_name(name) {
+            return Err(AocError::InvalidProfileName {
+                name: name.to_string(),
+            });
+        }
+        let sessions = match (name, config.profiles.get(name)) {
+            (_, Some(profile)) => profile_sessions(name, profile),
+            (DEFAULT_PROFILE, None) => SessionChain::default(),
+            (_, None) => {
+                return Err(AocError::UnknownProfile {
+                    name: name.to_string(),
+                    known: config.profiles.keys().cloned().collect(),
+                })
+            }
+        };
+        // All profiles share one schedule, since they talk to the same server.
+        let lock_file = Cache::root(config).join(throttle::LOCK_FILE);
+
+        Ok(Profile {
+            name: name.to_string(),
+            fetcher: Fetcher::builder()
+                .sessions(sessions)
+                .throttle(Throttle::default().with_lock_file(lock_file))
+                .with_env()
+                .build()?,
+            cache: Cache::from_config(config, name),
+        })
+    }
+
+    /// Profiles are loaded once per process and shared afterwards.
+    pub fn get(name: &str) -> Result<&'static Profile, AocError> {
+        static PROFILES: OnceLock<Mutex<HashMap<String, &'static Profile>>> = OnceLock::new();
+
+        let mut profiles = PROFILES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        if let Some(profile) = profiles.get(name) {
+            return Ok(profile);
+        }
+
+        let profile: &'static Profile =
+            Box::leak(Box::new(Profile::from_config(name, &Config::load()?)?));
+        profiles.insert(name.to_string(), profile);
+        Ok(profile)
+    }
+
+    pub fn selected() -> Result<&'static Profile, AocError> {
+        Profile::get(&selected_profile())
+    }
+
+    /// The default profile followed by every configured one.
+    pub fn names() -> Result<Vec<String>, AocError> {
+        let config = Config::load()?;
+        let mut names = vec![DEFAULT_PROFILE.to_string()];
+        names.extend(
+            config
+                .profiles
+                .into_keys()
+                .filter(|name| name != DEFAULT_PROFILE),
+        );
+        Ok(names)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn fetcher(&self) -> &Fetcher {
+        &self.fetcher
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Configured providers come first, then `AOC_SESSION_<NAME>` and `~/.config/aoc/sessions/<name>`.
+fn profile_sessions(name: &str, profile: &ProfileConfig) -> SessionChain {
+    let mut chain = SessionChain::new();
+    if let Some(var) = &profile.session_env {
+        chain = chain.with(EnvSession::new(var));
+    }
+    if let Some(path) = &profile.session_file {
+        chain = chain.with(FileSession::new(path));
+    }
+    if let Some(browser) = profile.browser {
+        chain = chain.with(BrowserSession::new(browser));
+    }
+
+    let env_name = name
+        .to_uppercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    chain = chain.with(EnvSession::new(format!("AOC_SESSION_{env_name}")));
+    if let Some(dir) = Config::dir() {
+        chain = chain.with(FileSession::new(dir.join("sessions").join(name)));
+    }
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn unknown_profile() {
+        let config = Config::parse("[profiles.alice]\n").unwrap();
+        assert!(matches!(
+            Profile::from_config("bob", &config),
+            Err(AocError::UnknownProfile { known, .. }) if known == ["alice"]
+        ));
+    }
+
+    #[rstest]
+    #[case("../x")]
+    #[case("a/b")]
+    #[case("")]
+    #[case(".")]
+    fn invalid_profile_name(#[case] name: &str) {
+        let config = Config::parse(&format!("[profiles.{name:?}]\n")).unwrap();
+        assert!(matches!(
+            Profile::from_config(name, &config),
+            Err(AocError::InvalidProfileName { .. })
+        ));
+    }
+
+    #[rstest]
+    fn profiles_have_separate_caches() {
+        let config = Config::parse("cache_dir = \"/tmp/aoc\"\n[profiles.alice]\n").unwrap();
+        let default = Profile::from_config(DEFAULT_PROFILE, &config).unwrap();
+        let alice = Profile::from_config("alice", &config).unwrap();
+
+        if env::var_os("AOC_CACHE_DIR").is_none() {
+            assert_eq!(default.cache().dir(), std::path::Path::new("/tmp/aoc"));
+        }
+        assert_eq!(
+            alice.cache().dir(),
+            default.cache().dir().join("profiles").join("alice")
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::bench::{self, BenchOptions, History, REGRESSION_THRESHOLD};
+use crate::cache::Cache;
+use crate::config::Config;
+use crate::{AocError, ParseError, Part, Solution};
+use std::fmt::Write;
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+/// A type-erased [`Solution`], so days can be listed together.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub input: &'static str,
+    pub solve: fn(&'static str, Option<Part>) -> Result<Run, ParseError>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            input: S::INPUT,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Solves the given part, or both if `part` is `None`.
+    pub fn run(&self, input: &'static str, part: Option<Part>) -> Result<Run, ParseError> {
+        (self.solve)(input, part)
+    }
+
+    /// What every day's binary does.
+    pub fn main(&self) -> ExitCode {
+        match self.run(self.input, None) {
+            Ok(run) => {
+                for answer in run.answers {
+                    println!("Part{}: {}", answer.part, answer.value);
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{}", self.parse_failure(&err));
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    fn parse_failure(&self, err: &ParseError) -> String {
+        format!(
+            "Couldn't parse the input of {} day {}:\n{err}",
+            self.year, self.day
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Run {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        self.answers.iter().find(|answer| answer.part == part)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .answers
+                .iter()
+                .map(|answer| answer.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+/// Times parsing and the selected parts.
+fn solve<S: Solution>(input: &'static str, part: Option<Part>) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let data = S::try_parse(input)?;
+    let parse = start.elapsed();
+
+    let mut answers = Vec::new();
+    if part != Some(Part::Two) {
+        answers.push(timed(Part::One, || S::part1(&data).to_string()));
+    }
+    if S::HAS_PART2 && part != Some(Part::One) {
+        answers.push(timed(Part::Two, || S::part2(&data).to_string()));
+    }
+
+    Ok(Run { parse, answers })
+}
+
+fn timed(part: Part, solve: impl FnOnce() -> String) -> Answer {
+    let start = Instant::now();
+    let value = solve();
+    Answer {
+        part,
+        value,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Arguments of a year's runner binary:
+/// `(<day> | --all) [--part <part>] [--input <path>] [--bench [--warmup <n>] [--samples <n>]]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunArgs {
+    /// `None` runs every day.
+    pub day: Option<u32>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub bench: Option<BenchOptions>,
+}
+
+impl RunArgs {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<RunArgs, String> {
+        let mut run_args = RunArgs::default();
+        let mut all = false;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value."));
+            let count = |value: String| {
+                value
+                    .parse::<u32>()
+                    .map_err(|_| format!("{value} isn't a count."))
+            };
+            match arg.as_str() {
+                "--all" => all = true,
+                "--bench" => {
+                    run_args.bench.get_or_insert_default();
+                }
+                "--warmup" => {
+                    run_args.bench.get_or_insert_default().warmup = count(value("--warmup")?)?;
+                }
+                "--samples" => {
+                    run_args.bench.get_or_insert_default().samples = count(value("--samples")?)?;
+                }
+                "--part" => {
+                    let part = value("--part")?;
+                    let part: u8 = part
+                        .parse()
+                        .map_err(|_| format!("{part} isn't a part number."))?;
+                    run_args.part = Some(Part::try_from(part)?);
+                }
+                "--input" => run_args.input = Some(value("--input")?.into()),
+                day => {
+                    let day = day.parse().map_err(|_| format!("{day} isn't a day."))?;
+                    run_args.day = Some(day);
+                }
+            }
+        }
+
+        match (run_args.day, all) {
+            (None, false) => Err("Pass a day or --all.".to_string()),
+            (Some(_), true) => Err("Pass either a day or --all.".to_string()),
+            (None, true) if run_args.input.is_some() => {
+                Err("--input needs a single day.".to_string())
+            }
+            _ => Ok(run_args),
+        }
+    }
+
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = match self.day {
+            Some(day) => vec![day.to_string()],
+            None => vec!["--all".to_string()],
+        };
+        if let Some(part) = self.part {
+            args.extend(["--part".to_string(), part.to_string()]);
+        }
+        if let Some(input) = &self.input {
+            args.extend(["--input".to_string(), input.to_string_lossy().into_owned()]);
+        }
+        if let Some(options) = self.bench {
+            args.extend([
+                "--bench".to_string(),
+                "--warmup".to_string(),
+                options.warmup.to_string(),
+                "--samples".to_string(),
+                options.samples.to_string(),
+            ]);
+        }
+        args
+    }
+}
+
+/// The `main` of a year's runner binary.
+pub fn main(days: &[Day]) -> ExitCode {
+    let args = match RunArgs::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let days: Vec<&Day> = days
+        .iter()
+        .filter(|day| args.day.is_none_or(|selected| day.day == selected))
+        .collect();
+    if days.is_empty() {
+        eprintln!(
+            "There is no solution for day {}.",
+            args.day.unwrap_or_default()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let input = match args.input.as_deref().map(read_input).transpose() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.report());
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(options) = args.bench {
+        return run_benchmarks(&days, input, args.part, options);
+    }
+
+    // A failing day shouldn't hide the results of all others.
+    let mut runs = Vec::new();
+    let mut failed = Vec::new();
+    for day in days {
+        match panic::catch_unwind(|| day.run(input.unwrap_or(day.input), args.part)) {
+            Ok(Ok(run)) => runs.push((day, run)),
+            Ok(Err(err)) => failed.push(day.parse_failure(&err)),
+            Err(_) => failed.push(format!("{} day {} panicked.", day.year, day.day)),
+        }
+    }
+
+    if !runs.is_empty() {
+        print!("{}", table(&runs));
+    }
+    for failure in &failed {
+        eprintln!("{failure}");
+    }
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_benchmarks(
+    days: &[&Day],
+    input: Option<&'static str>,
+    part: Option<Part>,
+    options: BenchOptions,
+) -> ExitCode {
+    let mut history = match Config::load()
+        .and_then(|config| History::load(Cache::root(&config).join(bench::HISTORY_FILE)))
+    {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("{}", err.report());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut measurements = Vec::new();
+    let mut failed = Vec::new();
+    for day in days {
+        let input = input.unwrap_or(day.input);
+        match panic::catch_unwind(|| bench::bench(day, input, part, options)) {
+            Ok(Ok(measurement)) => measurements.push(measurement),
+            Ok(Err(err)) => failed.push(day.parse_failure(&err)),
+            Err(_) => failed.push(format!("{} day {} panicked.", day.year, day.day)),
+        }
+    }
+
+    print!("{}", bench::table(&measurements));
+    for measurement in &measurements {
+        let Some(previous) = history.previous(measurement) else {
+            continue;
+        };
+        for regression in bench::regressions(previous, measurement, REGRESSION_THRESHOLD) {
+            println!(
+                "{} day {} {} regressed: {} -> {} (+{:.0}%)",
+                measurement.year,
+                measurement.day,
+                regression.stage,
+                format_duration(regression.before),
+                format_duration(regression.after),
+                regression.slowdown() * 100.0
+            );
+        }
+    }
+    for failure in &failed {
+        eprintln!("{failure}");
+    }
+
+    if let Err(err) = history.record(measurements) {
+        eprintln!("{}", err.report());
+        return ExitCode::FAILURE;
+    }
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Reads a custom input. It is leaked, since solutions may borrow from their input.
+pub fn read_input(path: &Path) -> Result<&'static str, AocError> {
+    let input = fs::read_to_string(path).map_err(|source| AocError::InputIo {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(Box::leak(input.into_boxed_str()))
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Renders the answers and timings of several days as a table.
+pub fn table(runs: &[(&Day, Run)]) -> String {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Part 1".to_string(),
+        "Time".to_string(),
+        "Part 2".to_string(),
+        "Time".to_string(),
+        "Total".to_string(),
+    ]];
+    for (day, run) in runs {
+        let cells = |part| match run.answer(part) {
+            Some(answer) => (answer.value.clone(), format_duration(answer.elapsed)),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        let (part1, time1) = cells(Part::One);
+        let (part2, time2) = cells(Part::Two);
+        rows.push([
+            format!("{} {:02}", day.year, day.day),
+            part1,
+            time1,
+            part2,
+            time2,
+            format_duration(run.total()),
+        ]);
+    }
+    let total: Duration = runs.iter().map(|(_, run)| run.total()).sum();
+
+    // Timings are right-aligned, everything else left-aligned.
+    let mut table = render_table(&rows, |column| matches!(column, 2 | 4 | 5));
+    if runs.len() > 1 {
+        writeln!(table, "Total: {}", format_duration(total)).unwrap();
+    }
+    table
+}
+
+/// Lays out rows with a rule below the header. Only body cells are right-aligned.
+pub(crate) fn render_table<const N: usize>(
+    rows: &[[String; N]],
+    right_aligned: impl Fn(usize) -> bool,
+) -> String {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for (i, row) in rows.iter().enumerate() {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                if i > 0 && right_aligned(column) {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        writeln!(table, "{}", line.join(" | ").trim_end()).unwrap();
+        if i == 0 {
+            let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+            writeln!(table, "{}", rule.join("-+-")).unwrap();
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use std::fmt::Display;
+
+    use super::*;
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const YEAR: u32 = 2024;
+        const DAY: u32 = 1;
+        const INPUT: &'static str = "1\n2\n3\n";
+
+        type Input = Vec<u32>;
+
+        fn try_parse(input: &'static str) -> Result<Vec<u32>, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| ParseError::at(input, line, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<u32>) -> impl Display {
+            numbers.iter().sum::<u32>()
+        }
+
+        fn part2(numbers: &Vec<u32>) -> impl Display {
+            numbers.iter().product::<u32>()
+        }
+    }
+
+    const DAY: Day = Day::of::<Numbers>();
+
+    #[rstest]
+    #[case(None, vec![(Part::One, "6"), (Part::Two, "6")])]
+    #[case(Some(Part::One), vec![(Part::One, "6")])]
+    #[case(Some(Part::Two), vec![(Part::Two, "6")])]
+    fn run_test(#[case] part: Option<Part>, #[case] expected: Vec<(Part, &str)>) {
+        let run = DAY.run(DAY.input, part).unwrap();
+        let answers: Vec<(Part, &str)> = run
+            .answers
+            .iter()
+            .map(|answer| (answer.part, answer.value.as_str()))
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    #[rstest]
+    fn custom_input() {
+        let run = DAY.run("4\n5\n", Some(Part::Two)).unwrap();
+        assert_eq!(run.answer(Part::Two).unwrap().value, "20");
+    }
+
+    #[rstest]
+    fn malformed_input() {
+        let err = DAY.run("4\nfive\n", None).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(DAY
+            .parse_failure(&err)
+            .starts_with("Couldn't parse the input of 2024 day 1:\n"));
+    }
+
+    #[rstest]
+    #[case(&["7"], Ok(RunArgs { day: Some(7), ..RunArgs::default() }))]
+    #[case(&["--all", "--part", "2"], Ok(RunArgs { part: Some(Part::Two), ..RunArgs::default() }))]
+    #[case(&["7", "--input", "in.txt"], Ok(RunArgs { day: Some(7), input: Some("in.txt".into()), ..RunArgs::default() }))]
+    #[case(&[], Err(()))]
+    #[case(&["7", "--all"], Err(()))]
+    #[case(&["--all", "--input", "in.txt"], Err(()))]
+    #[case(&["7", "--part", "3"], Err(()))]
+    #[case(&["7", "--bench"], Ok(RunArgs { day: Some(7), bench: Some(BenchOptions::default()), ..RunArgs::default() }))]
+    #[case(&["--all", "--samples", "5"], Ok(RunArgs { bench: Some(BenchOptions { samples: 5, ..BenchOptions::default() }), ..RunArgs::default() }))]
+    #[case(&["7", "--warmup", "-1"], Err(()))]
+    #[case(&["seven"], Err(()))]
+    fn run_args_test(#[case] args: &[&str], #[case] expected: Result<RunArgs, ()>) {
+        let parsed = RunArgs::parse(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(parsed.clone().map_err(|_| ()), expected);
+        if let Ok(parsed) = parsed {
+            assert_eq!(RunArgs::parse(parsed.to_args()), Ok(parsed));
+        }
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999ns")]
+    #[case(Duration::from_nanos(12_345), "12.3µs")]
+    #[case(Duration::from_micros(4_560), "4.56ms")]
+    #[case(Duration::from_millis(1_234), "1.23s")]
+    fn format_duration_test(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(format_duration(duration), expected);
+    }
+
+    #[rstest]
+    fn table_test() {
+        let run = Run {
+            parse: Duration::from_micros(1),
+            answers: vec![Answer {
+                part: Part::One,
+                value: "6".to_string(),
+                elapsed: Duration::from_micros(2),
+            }],
+        };
+        assert_eq!(
+            table(&[(&DAY, run)]),
+            "Day     | Part 1 | Time  | Part 2 | Time | Total\n\
+             --------+--------+-------+--------+------+------\n\
+             2024 01 | 6      | 2.0µs | -      |    - | 3.0µs\n"
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a search: the cheapest known cost of every reached state, all of their optimal
+/// predecessors, and the goal states reached at the lowest cost.
+///
+/// Costs are only final for states that were expanded before the search stopped at the goals.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// All goal states that were reached at the lowest cost, in the order they were found.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest goal, or `None` if no goal is reachable.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goals.first()?)
+    }
+
+    /// The states before `state` on its cheapest paths.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.costs.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// One cheapest path from a start to the first goal.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goals.first()?)
+    }
+
+    /// Every cheapest path from a start to `state`, which can be exponentially many.
+    pub fn all_paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return Vec::new();
+        }
+        let predecessors = self.predecessors(state);
+        if predecessors.is_empty() {
+            return vec![vec![state.clone()]];
+        }
+        predecessors
+            .iter()
+            .flat_map(|previous| self.all_paths_to(previous))
+            .map(|mut path| {
+                path.push(state.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// The states on any cheapest path to one of `targets`.
+    pub fn on_any_path_to(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|target| self.costs.contains_key(target))
+            .collect();
+        let mut states = HashSet::new();
+        while let Some(state) = stack.pop() {
+            if states.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        states
+    }
+
+    /// The states on any cheapest path to any of the goals.
+    pub fn on_any_path(&self) -> HashSet<S> {
+        self.on_any_path_to(self.goals.iter().cloned())
+    }
+
+    fn reach(&mut self, state: S, from: Option<&S>, cost: C) {
+        self.costs.insert(state.clone(), cost);
+        self.predecessors
+            .insert(state, from.into_iter().cloned().collect());
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+///
+/// Stops once all goals at the lowest distance are found; pass `|_| false` as `is_goal` to explore
+/// everything reachable.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.reach(start.clone(), None, 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if search.goal_cost().is_some_and(|best| distance > best) {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state.clone());
+        }
+        for next in successors(&state) {
+            match search.costs.get(&next) {
+                None => {
+                    search.reach(next.clone(), Some(&state), distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+                Some(&known) if known == distance + 1 => {
+                    search
+                        .predecessors
+                        .get_mut(&next)
+                        .unwrap()
+                        .push(state.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm over `successors` returning `(state, cost)` pairs.
+///
+/// Stops once all goals at the lowest cost are found; pass `|_| false` as `is_goal` to explore
+/// everything reachable.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, which only finds the cheapest paths if `heuristic` never overestimates the
+/// remaining cost.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search {
+        costs: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+    };
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.costs.contains_key(&start) {
+            search.reach(start.clone(), None, C::default());
+            heap.push(Entry {
+                priority: heuristic(&start),
+                cost: C::default(),
+                state: start,
+            });
+        }
+    }
+
+    while let Some(Entry {
+        priority,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if search.costs[&state] < cost {
+            continue;
+        }
+        if search.goal_cost().is_some_and(|best| priority > best) {
+            break;
+        }
+        if is_goal(&state) {
+            search.goals.push(state.clone());
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match search.costs.get(&next) {
+                Some(&known) if known < next_cost => {}
+                Some(&known) if known == next_cost => {
+                    search
+                        .predecessors
+                        .get_mut(&next)
+                        .unwrap()
+                        .push(state.clone());
+                }
+                _ => {
+                    search.reach(next.clone(), Some(&state), next_cost);
+                    heap.push(Entry {
+                        priority: next_cost + heuristic(&next),
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// A heap entry ordered so that the lowest priority is popped first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{Dir4, Grid, Point2};
+
+    const MAZE: &str = "\
+        ...#\n\
+        .#..\n\
+        ...#\n\
+        #...\n";
+
+    fn open_neighbors(grid: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbors4(position)
+            .filter(|&next| grid[next] == '.')
+            .collect()
+    }
+
+    #[rstest]
+    fn bfs_test() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let search = bfs(
+            [(0, 0)],
+            |&position| open_neighbors(&grid, position),
+            |&position| position == (3, 3),
+        );
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.path().unwrap().len(), 7);
+        assert_eq!(search.all_paths_to(&(3, 3)).len(), 3);
+        assert_eq!(search.on_any_path().len(), 11);
+
+        let search = bfs(
+            [(0, 0)],
+            |&position| open_neighbors(&grid, position),
+            |_| false,
+        );
+        assert_eq!(search.costs().len(), 12);
+        assert!(search.goals().is_empty());
+        assert_eq!(search.cost(&(3, 1)), Some(4));
+    }
+
+    /// Weighted edges where the direct route is more expensive than the detour.
+    fn edges(state: &char) -> Vec<(char, u32)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 5), ('d', 2)],
+            'b' => vec![('c', 1)],
+            'd' => vec![('c', 0)],
+            'c' => vec![('e', 3)],
+            _ => vec![],
+        }
+    }
+
+    #[rstest]
+    fn dijkstra_test() {
+        let search = dijkstra(['a'], edges, |&state| state == 'e');
+        assert_eq!(search.goal_cost(), Some(5));
+        assert_eq!(search.cost(&'c'), Some(2));
+        assert_eq!(search.predecessors(&'c'), &['b', 'd']);
+        assert_eq!(
+            search.all_paths_to(&'e'),
+            vec![vec!['a', 'b', 'c', 'e'], vec!['a', 'd', 'c', 'e']]
+        );
+        assert_eq!(
+            search.on_any_path(),
+            HashSet::from(['a', 'b', 'c', 'd', 'e'])
+        );
+
+        let search = dijkstra(['a'], edges, |&state| state == 'z');
+        assert_eq!(search.goal_cost(), None);
+        assert_eq!(search.path(), None);
+    }
+
+    #[rstest]
+    fn multiple_goals() {
+        let search = dijkstra([0_u32], |&n| [(n + 1, 1), (n + 2, 2)], |&n| n >= 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.goals(), &[3]);
+
+        let search = bfs([0_u32], |&n| [n + 1, n + 2], |&n| n >= 3);
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.goals().len(), 2);
+    }
+
+    #[rstest]
+    fn astar_test() {
+        // On an open plane, the heuristic keeps A* on the straight line to the goal.
+        let goal = Point2::new(5, 0);
+        let mut expanded = 0;
+        let search = astar(
+            [Point2::new(0, 0)],
+            |&point| {
+                expanded += 1;
+                Dir4::ALL.map(|dir| (point + dir, 1))
+            },
+            |point| point.manhattan(goal),
+            |&point| point == goal,
+        );
+        assert_eq!(search.goal_cost(), Some(5));
+        assert_eq!(search.path().unwrap().len(), 6);
+        assert_eq!(expanded, 6);
+    }
+}
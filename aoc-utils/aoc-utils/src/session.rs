@@ -0,0 +1,316 @@
+use crate::cache::sha256;
+use crate::config::Config;
+use crate::AocError;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+const AOC_DOMAIN: &str = "adventofcode.com";
+const SESSION_ENV_VAR: &str = "AOC_SESSION";
+const MIN_TOKEN_LENGTH: usize = 64;
+
+pub type ProviderResult = Result<Option<String>, Box<dyn Error + Send + Sync>>;
+
+pub trait SessionProvider: Send + Sync {
+    fn name(&self) -> String;
+
+    /// Returns `Ok(None)` if this provider isn't configured.
+    fn load(&self) -> ProviderResult;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub token: String,
+    pub provider: String,
+}
+
+impl Session {
+    /// Identifies the account without revealing the token.
+    pub fn fingerprint(&self) -> String {
+        sha256(self.token.as_bytes())[..12].to_string()
+    }
+}
+
+impl Display for Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "session from {}", self.provider)
+    }
+}
+
+pub struct ExplicitSession(pub String);
+
+impl SessionProvider for ExplicitSession {
+    fn name(&self) -> String {
+        "explicit override".to_string()
+    }
+
+    fn load(&self) -> ProviderResult {
+        Ok(Some(self.0.clone()))
+    }
+}
+
+pub struct EnvSession {
+    var: String,
+}
+
+impl EnvSession {
+    pub fn new(var: impl Into<String>) -> Self {
+        EnvSession { var: var.into() }
+    }
+}
+
+impl Default for EnvSession {
+    fn default() -> Self {
+        EnvSession::new(SESSION_ENV_VAR)
+    }
+}
+
+impl SessionProvider for EnvSession {
+    fn name(&self) -> String {
+        format!("environment variable {}", self.var)
+    }
+
+    fn load(&self) -> ProviderResult {
+        match env::var(&self.var) {
+            Ok(token) => Ok(Some(token)),
+            Err(env::VarError::NotPresent) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+pub struct FileSession {
+    path: PathBuf,
+}
+
+impl FileSession {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileSession { path: path.into() }
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        Config::dir().map(|dir| dir.join("session"))
+    }
+}
+
+impl SessionProvider for FileSession {
+    fn name(&self) -> String {
+        format!("token file {}", self.path.display())
+    }
+
+    fn load(&self) -> ProviderResult {
+        match fs::read_to_string(&self.path) {
+            Ok(token) => Ok(Some(token)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Browser {
+    Firefox,
+    LibreWolf,
+    Zen,
+    Chrome,
+    Chromium,
+    Brave,
+    Edge,
+    Opera,
+    Vivaldi,
+    Arc,
+    /// Every browser supported by rookie on this platform.
+    Any,
+}
+
+impl Browser {
+    fn cookies(self, domains: Option<Vec<String>>) -> rookie::Result<Vec<rookie::enums::Cookie>> {
+        match self {
+            Browser::Firefox => rookie::firefox(domains),
+            Browser::LibreWolf => rookie::librewolf(domains),
+            Browser::Zen => rookie::zen(domains),
+            Browser::Chrome => rookie::chrome(domains),
+            Browser::Chromium => rookie::chromium(domains),
+            Browser::Brave => rookie::brave(domains),
+            Browser::Edge => rookie::edge(domains),
+            Browser::Opera => rookie::opera(domains),
+            Browser::Vivaldi => rookie::vivaldi(domains),
+            Browser::Arc => rookie::arc(domains),
+            Browser::Any => rookie::load(domains),
+        }
+    }
+}
+
+pub struct BrowserSession {
+    browser: Browser,
+}
+
+impl BrowserSession {
+    pub fn new(browser: Browser) -> Self {
+        BrowserSession { browser }
+    }
+}
+
+impl SessionProvider for BrowserSession {
+    fn name(&self) -> String {
+        match self.browser {
+            Browser::Any => "browser cookies".to_string(),
+            browser => format!("{browser:?} cookies"),
+        }
+    }
+
+    fn load(&self) -> ProviderResult {
+        let cookies = self.browser.cookies(Some(vec![AOC_DOMAIN.to_string()]))?;
+        Ok(cookies
+            .into_iter()
+            .find(|cookie| cookie.name == "session")
+            .map(|cookie| cookie.value))
+    }
+}
+
+pub struct SessionChain {
+    providers: Vec<Box<dyn SessionProvider>>,
+}
+
+impl SessionChain {
+    pub fn new() -> Self {
+        SessionChain {
+            providers: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, provider: impl SessionProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// Puts an explicit token in front of all other providers.
+    pub fn with_override(mut self, token: impl Into<String>) -> Self {
+        self.providers
+            .insert(0, Box::new(ExplicitSession(token.into())));
+        self
+    }
+
+    pub fn resolve(&self) -> Result<Session, AocError> {
+        let mut attempts = Vec::new();
+        let mut last_error = None;
+
+        for provider in &self.providers {
+            match provider.load() {
+                Ok(Some(token)) => {
+                    let token = normalize_token(&token);
+                    validate_token(token).map_err(|reason| AocError::InvalidSession {
+                        provider: provider.name(),
+                        reason,
+                    })?;
+                    return Ok(Session {
+                        token: token.to_string(),
+                        provider: provider.name(),
+                    });
+                }
+                Ok(None) => attempts.push(format!("{}: not set", provider.name())),
+                Err(err) => {
+                    attempts.push(format!("{}: {err}", provider.name()));
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        Err(AocError::SessionMissing {
+            attempts,
+            source: last_error,
+        })
+    }
+}
+
+impl Default for SessionChain {
+    fn default() -> Self {
+        let chain = SessionChain::new().with(EnvSession::default());
+        let chain = match FileSession::default_path() {
+            Some(path) => chain.with(FileSession::new(path)),
+            None => chain,
+        };
+        chain.with(BrowserSession::new(Browser::Any))
+    }
+}
+
+fn normalize_token(token: &str) -> &str {
+    let token = token.trim();
+    token.strip_prefix("session=").unwrap_or(token)
+}
+
+fn validate_token(token: &str) -> Result<(), String> {
+    if token.is_empty() {
+        return Err("token is empty".to_string());
+    }
+    if let Some(c) = token.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("token contains non-hexadecimal character {c:?}"));
+    }
+    if token.len() < MIN_TOKEN_LENGTH {
+        return Err(format!(
+            "token is {} characters long, expected at least {MIN_TOKEN_LENGTH}",
+            token.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const TOKEN: &str = "53616c7465645f5f0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    struct Unset;
+
+    impl SessionProvider for Unset {
+        fn name(&self) -> String {
+            "unset".to_string()
+        }
+
+        fn load(&self) -> ProviderResult {
+            Ok(None)
+        }
+    }
+
+    #[rstest]
+    #[case(TOKEN, Ok(()))]
+    #[case("", Err(()))]
+    #[case("abc", Err(()))]
+    #[case(&TOKEN.replace('a', "z"), Err(()))]
+    fn validate_token_test(#[case] token: &str, #[case] expected: Result<(), ()>) {
+        assert_eq!(validate_token(token).map_err(|_| ()), expected);
+    }
+
+    #[rstest]
+    fn chain_reports_provider() {
+        let chain = SessionChain::new()
+            .with(Unset)
+            .with(ExplicitSession(format!(" session={TOKEN}\n")));
+        let session = chain.resolve().unwrap();
+        assert_eq!(session.token, TOKEN);
+        assert_eq!(session.provider, "explicit override");
+    }
+
+    #[rstest]
+    fn chain_override_comes_first() {
+        let chain = SessionChain::new()
+            .with(ExplicitSession("invalid".to_string()))
+            .with_override(TOKEN);
+        assert_eq!(chain.resolve().unwrap().token, TOKEN);
+    }
+
+    #[rstest]
+    fn chain_without_session() {
+        let chain = SessionChain::new().with(Unset);
+        assert!(matches!(
+            chain.resolve(),
+            Err(AocError::SessionMissing { attempts, .. }) if attempts == ["unset: not set"]
+        ));
+    }
+}
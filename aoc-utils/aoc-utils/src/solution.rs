@@ -0,0 +1,136 @@
+use crate::runner::format_duration;
+use crate::{ParseError, Part};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// A day's puzzle. Implement it with `#[aoc_macros::solution(year, day)]`, which fills in
+/// `YEAR`, `DAY`, `INPUT` and `HAS_PART2`, and implement either `parse` or `try_parse`. Without
+/// the macro, `try_parse` is required.
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+    const INPUT: &'static str;
+    const HAS_PART2: bool = true;
+
+    type Input;
+
+    fn parse(input: &'static str) -> Self::Input {
+        Self::try_parse(input).unwrap_or_else(|err| panic!("Couldn't parse input:\n{err}"))
+    }
+
+    /// Reports malformed input instead of panicking, which is what the runners call.
+    fn try_parse(input: &'static str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Display;
+
+    /// Only the last day of a year lacks a second part.
+    fn part2(_input: &Self::Input) -> impl Display {
+        ""
+    }
+}
+
+/// Compares one part of a solution against its accepted answer, see `aoc_macros::test_answers!`.
+/// With a `budget`, the part also has to finish within it.
+pub fn check_answer<S: Solution>(
+    part: Part,
+    answers: (Option<String>, Option<String>),
+    solve: impl FnOnce() -> String,
+    budget: Option<Duration>,
+) {
+    let answer = match part {
+        Part::One => answers.0,
+        Part::Two => answers.1,
+    };
+    if part == Part::Two && !S::HAS_PART2 {
+        assert!(answer.is_none(), "An answer has been submitted for part 2.");
+        return;
+    }
+
+    let answer =
+        answer.unwrap_or_else(|| panic!("The answer for part {part} hasn't been submitted yet."));
+    let start = Instant::now();
+    let result = solve();
+    let elapsed = start.elapsed();
+    assert_eq!(answer, result, "Part {part} is incorrect.");
+
+    if let Some(budget) = budget {
+        assert!(
+            elapsed <= budget,
+            "Part {part} took {}, more than its budget of {}.",
+            format_duration(elapsed),
+            format_duration(budget)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use std::thread;
+
+    use super::*;
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const YEAR: u32 = 2023;
+        const DAY: u32 = 25;
+        const INPUT: &'static str = "20";
+        const HAS_PART2: bool = false;
+
+        type Input = u64;
+
+        fn try_parse(input: &'static str) -> Result<u64, ParseError> {
+            input
+                .parse()
+                .map_err(|_| ParseError::at(input, input, "expected a number"))
+        }
+
+        fn part1(millis: &u64) -> impl Display {
+            thread::sleep(Duration::from_millis(*millis));
+            millis
+        }
+    }
+
+    fn check(answer1: &str, budget: Option<Duration>) {
+        let answers = (Some(answer1.to_string()), None);
+        let data = Slow::parse(Slow::INPUT);
+        check_answer::<Slow>(
+            Part::One,
+            answers,
+            || Slow::part1(&data).to_string(),
+            budget,
+        );
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(Duration::from_secs(10)))]
+    fn within_budget(#[case] budget: Option<Duration>) {
+        check("20", budget);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Part 1 took")]
+    fn over_budget() {
+        check("20", Some(Duration::from_millis(1)));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Part 1 is incorrect.")]
+    fn incorrect_answer() {
+        check("21", Some(Duration::from_millis(1)));
+    }
+
+    #[rstest]
+    #[case(None, false)]
+    #[case(Some("20"), true)]
+    fn missing_part2(#[case] answer2: Option<&str>, #[case] panics: bool) {
+        let answers = (Some("20".to_string()), answer2.map(str::to_string));
+        let result = std::panic::catch_unwind(|| {
+            check_answer::<Slow>(Part::Two, answers, || unreachable!(), None)
+        });
+        assert_eq!(result.is_err(), panics);
+    }
+}
@@ -0,0 +1,366 @@
+use crate::cache::{unix_time, update_json, Cache};
+use crate::{AocError, Fetcher, Profile};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn index(self) -> usize {
+        match self {
+            Part::One => 0,
+            Part::Two => 1,
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(part: u8) -> Result<Self, Self::Error> {
+        match part {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(format!("There is no part {part}, only 1 and 2.")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::TooHigh => write!(f, "That's not the right answer; it's too high."),
+            SubmitOutcome::TooLow => write!(f, "That's not the right answer; it's too low."),
+            SubmitOutcome::Wrong => write!(f, "That's not the right answer."),
+            SubmitOutcome::RateLimited { wait } => write!(
+                f,
+                "You gave an answer too recently; wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmitOutcome::AlreadySolved => write!(f, "This part has already been solved."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: Part,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct Submissions {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Submissions {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AocError> {
+        let path = path.into();
+        let attempts = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                serde_json::from_str(&contents).map_err(|source| AocError::CacheFormat {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(AocError::cache_io(&path)(err)),
+        };
+        Ok(Submissions { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Explains why `answer` can't be correct based on earlier attempts.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        let numeric_answer: Option<i128> = answer.parse().ok();
+
+        for attempt in self.attempts.iter().filter(|attempt| attempt.part == part) {
+            let numeric_attempt: Option<i128> = attempt.answer.parse().ok();
+            match (&attempt.outcome, numeric_answer, numeric_attempt) {
+                (SubmitOutcome::Correct, _, _) => {
+                    return Err(format!(
+                        "Part {part} has already been solved with {}.",
+                        attempt.answer
+                    ));
+                }
+                (SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong, _, _)
+                    if attempt.answer == answer =>
+                {
+                    return Err(format!(
+                        "{answer} has already been submitted and was wrong."
+                    ));
+                }
+                (SubmitOutcome::TooHigh, Some(answer), Some(high)) if answer >= high => {
+                    return Err(format!(
+                        "{answer} isn't below the known upper bound {high}."
+                    ));
+                }
+                (SubmitOutcome::TooLow, Some(answer), Some(low)) if answer <= low => {
+                    return Err(format!("{answer} isn't above the known lower bound {low}."));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(
+        &mut self,
+        part: Part,
+        answer: &str,
+        outcome: SubmitOutcome,
+    ) -> Result<(), AocError> {
+        let attempt = Attempt {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: unix_time(),
+        };
+        // Keeps the attempts other processes recorded since this log was loaded.
+        self.attempts = update_json(&self.path, |attempts: &mut Vec<Attempt>| {
+            attempts.push(attempt)
+        })?;
+        Ok(())
+    }
+}
+
+pub fn submit_answer(
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<SubmitOutcome, AocError> {
+    let profile = Profile::selected()?;
+    submit_answer_with(profile.fetcher(), profile.cache(), year, day, part, answer)
+}
+
+pub fn submit_answer_with(
+    fetcher: &Fetcher,
+    cache: &Cache,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+) -> Result<SubmitOutcome, AocError> {
+    let answer = answer.trim();
+    let mut submissions = Submissions::load(cache.submissions_path(year, day))?;
+    submissions
+        .check(part, answer)
+        .map_err(|reason| AocError::SubmissionRefused {
+            answer: answer.to_string(),
+            reason,
+        })?;
+
+    let page = fetcher.post_answer(year, day, part, answer)?;
+    let outcome =
+        parse_outcome(&page).map_err(|reason| AocError::PageParse { year, day, reason })?;
+
+    submissions.record(part, answer, outcome.clone())?;
+    if outcome == SubmitOutcome::Correct {
+        cache.invalidate(year, day)?;
+    }
+    Ok(outcome)
+}
+
+fn parse_outcome(page: &str) -> Result<SubmitOutcome, String> {
+    let article = page
+        .find("<article")
+        .and_then(|start| {
+            page[start..]
+                .find("</article>")
+                .map(|end| &page[start..start + end])
+        })
+        .ok_or("answer response contains no <article>")?;
+
+    if article.contains("That's the right answer") {
+        Ok(SubmitOutcome::Correct)
+    } else if article.contains("That's not the right answer") {
+        if article.contains("your answer is too high") {
+            Ok(SubmitOutcome::TooHigh)
+        } else if article.contains("your answer is too low") {
+            Ok(SubmitOutcome::TooLow)
+        } else {
+            Ok(SubmitOutcome::Wrong)
+        }
+    } else if article.contains("You gave an answer too recently") {
+        Ok(SubmitOutcome::RateLimited {
+            wait: parse_wait(article).unwrap_or_default(),
+        })
+    } else if article.contains("Did you already complete it?") {
+        Ok(SubmitOutcome::AlreadySolved)
+    } else {
+        Err("unrecognized answer response".to_string())
+    }
+}
+
+fn parse_wait(article: &str) -> Option<Duration> {
+    let start = article.find("You have ")? + "You have ".len();
+    let end = article[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in article[start..start + end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn article(text: &str) -> String {
+        format!("<main>\n<article><p>{text}</p></article>\n</main>")
+    }
+
+    #[rstest]
+    #[case("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.", SubmitOutcome::Correct)]
+    #[case(
+        "That's not the right answer; your answer is too high.  If you're stuck, ...",
+        SubmitOutcome::TooHigh
+    )]
+    #[case(
+        "That's not the right answer; your answer is too low.  If you're stuck, ...",
+        SubmitOutcome::TooLow
+    )]
+    #[case(
+        "That's not the right answer.  If you're stuck, ...",
+        SubmitOutcome::Wrong
+    )]
+    #[case("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 37s left to wait.", SubmitOutcome::RateLimited { wait: Duration::from_secs(37) })]
+    #[case("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.", SubmitOutcome::RateLimited { wait: Duration::from_secs(242) })]
+    #[case(
+        "You don't seem to be solving the right level.  Did you already complete it?",
+        SubmitOutcome::AlreadySolved
+    )]
+    fn parse_outcome_test(#[case] text: &str, #[case] expected: SubmitOutcome) {
+        assert_eq!(parse_outcome(&article(text)), Ok(expected));
+    }
+
+    #[rstest]
+    fn parse_outcome_without_article() {
+        assert!(parse_outcome("<html></html>").is_err());
+    }
+
+    fn submissions(attempts: &[(Part, &str, SubmitOutcome)]) -> Submissions {
+        Submissions {
+            path: PathBuf::new(),
+            attempts: attempts
+                .iter()
+                .map(|(part, answer, outcome)| Attempt {
+                    part: *part,
+                    answer: answer.to_string(),
+                    outcome: outcome.clone(),
+                    timestamp: 0,
+                })
+                .collect(),
+        }
+    }
+
+    #[rstest]
+    #[case(Part::One, "42", false)]
+    #[case(Part::One, "100", false)]
+    #[case(Part::One, "150", false)]
+    #[case(Part::One, "10", false)]
+    #[case(Part::One, "50", true)]
+    #[case(Part::One, "abc", false)]
+    #[case(Part::One, "xyz", true)]
+    #[case(Part::Two, "7", false)]
+    #[case(Part::Two, "8", false)]
+    fn check_test(#[case] part: Part, #[case] answer: &str, #[case] allowed: bool) {
+        let submissions = submissions(&[
+            (Part::One, "42", SubmitOutcome::Wrong),
+            (Part::One, "100", SubmitOutcome::TooHigh),
+            (Part::One, "10", SubmitOutcome::TooLow),
+            (Part::One, "abc", SubmitOutcome::Wrong),
+            (
+                Part::One,
+                "60",
+                SubmitOutcome::RateLimited {
+                    wait: Duration::from_secs(5),
+                },
+            ),
+            (Part::Two, "7", SubmitOutcome::Correct),
+        ]);
+        assert_eq!(submissions.check(part, answer).is_ok(), allowed);
+    }
+
+    #[rstest]
+    fn concurrent_records_are_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("2024").join("03-submissions.json");
+
+        let mut first = Submissions::load(&path).unwrap();
+        let mut second = Submissions::load(&path).unwrap();
+        first
+            .record(Part::One, "42", SubmitOutcome::TooLow)
+            .unwrap();
+        second
+            .record(Part::One, "50", SubmitOutcome::TooHigh)
+            .unwrap();
+        assert_eq!(second.attempts().len(), 2);
+
+        std::thread::scope(|scope| {
+            for answer in 43..50 {
+                let path = &path;
+                scope.spawn(move || {
+                    Submissions::load(path)
+                        .unwrap()
+                        .record(Part::One, &answer.to_string(), SubmitOutcome::Wrong)
+                        .unwrap()
+                });
+            }
+        });
+        assert_eq!(Submissions::load(&path).unwrap().attempts().len(), 9);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::AocError;
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+pub const LOCK_FILE: &str = "throttle.lock";
+
+/// The earliest time (in ms since the epoch) the next request of this process may be sent,
+/// per lock file.
+static NEXT_REQUEST: Mutex<BTreeMap<Option<PathBuf>, u64>> = Mutex::new(BTreeMap::new());
+
+/// Spaces out requests within this process and, with a lock file, across processes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Throttle {
+    min_interval: Duration,
+    lock_file: Option<PathBuf>,
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Self {
+        Throttle {
+            min_interval,
+            lock_file: None,
+        }
+    }
+
+    pub fn none() -> Self {
+        Throttle::new(Duration::ZERO)
+    }
+
+    /// Shares the schedule with every process using the same lock file.
+    pub fn with_lock_file(mut self, lock_file: impl Into<PathBuf>) -> Self {
+        self.lock_file = Some(lock_file.into());
+        self
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    pub fn lock_file(&self) -> Option<&Path> {
+        self.lock_file.as_deref()
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.min_interval.is_zero() && self.lock_file.is_none()
+    }
+
+    /// Blocks until a request may be sent and reserves the slot for it.
+    pub fn wait(&self) -> Result<(), AocError> {
+        self.schedule(|next| {
+            thread::sleep(Duration::from_millis(next).saturating_sub(since_epoch()));
+            to_ms(since_epoch() + self.min_interval)
+        })
+    }
+
+    /// Holds back all further requests for `delay`, e.g. after a `Retry-After` header.
+    pub fn defer(&self, delay: Duration) -> Result<(), AocError> {
+        self.schedule(|next| next.max(to_ms(since_epoch() + delay)))
+    }
+
+    /// Runs `update` on the time of the next request (in ms since the epoch) while holding
+    /// both the process-wide lock and the lock file.
+    fn schedule(&self, update: impl FnOnce(u64) -> u64) -> Result<(), AocError> {
+        if self.is_disabled() {
+            return Ok(());
+        }
+
+        let mut next_request = NEXT_REQUEST.lock().unwrap_or_else(|err| err.into_inner());
+        let process_next = next_request.get(&self.lock_file).copied().unwrap_or(0);
+
+        let next = match &self.lock_file {
+            Some(path) => {
+                let mut file = open_locked(path)?;
+                let next = update(read_next(path, &mut file)?.max(process_next));
+                write_next(path, &mut file, next)?;
+                next
+            }
+            None => update(process_next),
+        };
+
+        next_request.insert(self.lock_file.clone(), next);
+        Ok(())
+    }
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Throttle::new(DEFAULT_MIN_INTERVAL)
+    }
+}
+
+/// Opens `path`, creating it if needed, and waits for an exclusive lock on it. The lock is
+/// released when the file is dropped.
+pub(crate) fn open_locked(path: &Path) -> Result<File, AocError> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(AocError::cache_io(dir))?;
+    }
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .map_err(AocError::cache_io(path))?;
+    file.lock().map_err(AocError::cache_io(path))?;
+    Ok(file)
+}
+
+fn read_next(path: &Path, file: &mut File) -> Result<u64, AocError> {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .map_err(AocError::cache_io(path))?;
+    // A missing or garbled timestamp just means nobody has to wait.
+    Ok(contents.trim().parse().unwrap_or(0))
+}
+
+fn write_next(path: &Path, file: &mut File, next: u64) -> Result<(), AocError> {
+    file.set_len(0)
+        .and_then(|_| file.seek(SeekFrom::Start(0)))
+        .and_then(|_| file.write_all(next.to_string().as_bytes()))
+        .map_err(AocError::cache_io(path))
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Rounds up, so nobody starts a request too early.
+fn to_ms(duration: Duration) -> u64 {
+    duration.as_nanos().div_ceil(1_000_000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use std::time::Instant;
+
+    use super::*;
+
+    #[rstest]
+    fn lock_file_spaces_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock_file = dir.path().join(LOCK_FILE);
+        let first = Throttle::new(Duration::from_millis(200)).with_lock_file(&lock_file);
+        let second = Throttle::new(Duration::from_millis(200)).with_lock_file(&lock_file);
+
+        let start = Instant::now();
+        first.wait().unwrap();
+        second.wait().unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let next: u64 = std::fs::read_to_string(&lock_file)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(next > to_ms(since_epoch()));
+    }
+
+    #[rstest]
+    fn defer_never_moves_the_schedule_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock_file = dir.path().join(LOCK_FILE);
+        let throttle = Throttle::none().with_lock_file(&lock_file);
+
+        throttle.defer(Duration::from_secs(60)).unwrap();
+        throttle.defer(Duration::from_secs(1)).unwrap();
+
+        let next: u64 = std::fs::read_to_string(&lock_file)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(next >= to_ms(since_epoch()) + 59_000);
+    }
+}
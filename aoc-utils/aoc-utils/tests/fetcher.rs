@@ -0,0 +1,200 @@
+mod common;
+
+use aoc_utils::cache::Cache;
+use aoc_utils::fetch::RetryPolicy;
+use aoc_utils::session::SessionChain;
+use aoc_utils::submit::{submit_answer_with, Submissions};
+use aoc_utils::throttle::Throttle;
+use aoc_utils::{AocError, Fetcher, Part, SubmitOutcome};
+use common::{StubServer, TOKEN};
+use std::time::{Duration, Instant};
+
+fn fetcher(server: &StubServer, retry: RetryPolicy) -> Fetcher {
+    Fetcher::builder()
+        .base_url(&server.base_url)
+        .user_agent("aoc-utils tests")
+        .timeout(Duration::from_secs(5))
+        .retry(retry)
+        .throttle(Throttle::none())
+        .sessions(SessionChain::new().with_override(TOKEN))
+        .build()
+        .unwrap()
+}
+
+fn quick_retry(max_retries: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_retries,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+        max_retry_after: Duration::from_secs(5),
+    }
+}
+
+#[test]
+fn fetch_input_sends_session_and_user_agent() {
+    let server = StubServer::start(vec![(200, "1 2 3\n")]);
+    let input = fetcher(&server, RetryPolicy::none())
+        .fetch_input(2024, 7)
+        .unwrap();
+
+    assert_eq!(input, "1 2 3\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2024/day/7/input");
+    assert_eq!(
+        requests[0].header("cookie"),
+        Some(format!("session={TOKEN}").as_str())
+    );
+    assert_eq!(requests[0].header("user-agent"), Some("aoc-utils tests"));
+}
+
+#[test]
+fn server_errors_are_retried() {
+    let server = StubServer::start(vec![(500, "oops"), (503, "busy"), (200, "<html>")]);
+    let page = fetcher(&server, quick_retry(3))
+        .fetch_problem(2023, 1)
+        .unwrap();
+
+    assert_eq!(page, "<html>");
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn client_errors_are_not_retried() {
+    let server = StubServer::start(vec![(404, "Not Found"), (200, "unreachable")]);
+    let err = fetcher(&server, quick_retry(3))
+        .fetch_input(2030, 1)
+        .unwrap_err();
+
+    match err {
+        AocError::Http { status, body, .. } => {
+            assert_eq!(status, 404);
+            assert_eq!(body, "Not Found");
+        }
+        err => panic!("Unexpected error: {err:?}"),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn retries_are_bounded() {
+    let server = StubServer::start(vec![(500, "1"), (500, "2"), (500, "3")]);
+    let err = fetcher(&server, quick_retry(1))
+        .fetch_input(2024, 1)
+        .unwrap_err();
+
+    assert!(matches!(err, AocError::Http { status, .. } if status == 500));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn retry_after_is_respected() {
+    let server = StubServer::start_with_headers(vec![
+        (429, vec![("Retry-After", "1")], "slow down"),
+        (200, vec![], "1 2 3\n"),
+    ]);
+    let start = Instant::now();
+    let input = fetcher(&server, quick_retry(3))
+        .fetch_input(2024, 2)
+        .unwrap();
+
+    assert_eq!(input, "1 2 3\n");
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn long_retry_after_fails_fast() {
+    let server = StubServer::start_with_headers(vec![
+        (429, vec![("Retry-After", "3600")], "slow down"),
+        (200, vec![], "unreachable"),
+    ]);
+    let err = fetcher(&server, quick_retry(3))
+        .fetch_input(2024, 3)
+        .unwrap_err();
+
+    assert!(matches!(err, AocError::Http { status, .. } if status == 429));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn throttled_fetchers_share_a_lock_file() {
+    let server = StubServer::start(vec![(200, "a\n"), (200, "b\n")]);
+    let dir = tempfile::tempdir().unwrap();
+    let throttled = || {
+        Fetcher::builder()
+            .base_url(&server.base_url)
+            .throttle(
+                Throttle::new(Duration::from_millis(300))
+                    .with_lock_file(dir.path().join("throttle.lock")),
+            )
+            .sessions(SessionChain::new().with_override(TOKEN))
+            .build()
+            .unwrap()
+    };
+
+    let start = Instant::now();
+    throttled().fetch_input(2024, 4).unwrap();
+    throttled().fetch_input(2024, 5).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+fn submissions_are_recorded_and_not_repeated() {
+    let page = "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>";
+    let server = StubServer::start(vec![(200, page)]);
+    let fetcher = fetcher(&server, RetryPolicy::none());
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Cache::new(dir.path());
+
+    let outcome = submit_answer_with(&fetcher, &cache, 2024, 3, Part::One, "1000").unwrap();
+    assert_eq!(outcome, SubmitOutcome::TooHigh);
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2024/day/3/answer");
+    assert_eq!(requests[0].body, "level=1&answer=1000");
+
+    let err = submit_answer_with(&fetcher, &cache, 2024, 3, Part::One, "1200").unwrap_err();
+    assert!(matches!(err, AocError::SubmissionRefused { .. }));
+
+    let submissions = Submissions::load(cache.submissions_path(2024, 3)).unwrap();
+    assert_eq!(submissions.attempts().len(), 1);
+    assert_eq!(server.requests().len(), 1);
+}
+
+const ONE_STAR_PAGE: &str = "<main><article class=\"day-desc\"><h2>--- Day 2: Test ---</h2></article>\
+<p>Your puzzle answer was <code>17</code>.</p>\
+<p class=\"day-success\">The first half of this puzzle is complete! It provides one gold star: *</p></main>";
+
+#[test]
+fn incomplete_answers_are_cached_until_they_expire() {
+    let server = StubServer::start(vec![(200, ONE_STAR_PAGE), (200, ONE_STAR_PAGE)]);
+    let fetcher = fetcher(&server, RetryPolicy::none());
+    let dir = tempfile::tempdir().unwrap();
+
+    let cache = Cache::new(dir.path()).with_answers_ttl(Duration::from_secs(3600));
+    let answers = cache.answers(&fetcher, 2024, 2).unwrap();
+    assert_eq!(answers.stars, 1);
+    assert_eq!(answers.answer(Part::One), Some("17"));
+    cache.answers(&fetcher, 2024, 2).unwrap();
+    assert_eq!(server.requests().len(), 1);
+
+    let expired = Cache::new(dir.path()).with_answers_ttl(Duration::ZERO);
+    expired.answers(&fetcher, 2024, 2).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[test]
+fn invalidated_answers_are_fetched_again() {
+    let server = StubServer::start(vec![(200, ONE_STAR_PAGE), (200, ONE_STAR_PAGE)]);
+    let fetcher = fetcher(&server, RetryPolicy::none());
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Cache::new(dir.path());
+
+    cache.answers(&fetcher, 2024, 2).unwrap();
+    cache.invalidate(2024, 2).unwrap();
+    cache.answers(&fetcher, 2024, 2).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}